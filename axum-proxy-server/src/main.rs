@@ -1,5 +1,8 @@
 use std::collections::HashMap;
+use std::env;
+use std::future::IntoFuture;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use axum::body::Bytes;
 use axum::http::StatusCode;
 use axum::Json;
@@ -7,11 +10,18 @@ use axum::{Router, routing::post};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use tokio::net::TcpListener;
+use tokio::signal;
+use tokio::sync::watch;
+use tracing::{error, info, warn};
+use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 use axum::extract::State;
 
 
 type Cache = Arc<Mutex<HashMap<String, Bytes>>>;
 
+// 종료 signal 후 처리 중인 요청을 기다리는 기본 시간 (SHUTDOWN_TIMEOUT_SECS로 변경)
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 10;
+
 #[derive(Deserialize, Serialize, Debug)]
 struct DogData {
     // 품종
@@ -64,8 +74,43 @@ async fn proxy_handler_cached(
 }
 
 
+// Ctrl+C (SIGINT) 또는 SIGTERM을 기다린다.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
 #[tokio::main]
 async fn main() {
+    // RUST_LOG가 없으면 info 이상을 출력
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .init();
+
+    let shutdown_timeout = Duration::from_secs(
+        env::var("SHUTDOWN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_SECS),
+    );
+
     let state: Cache = Arc::new(Mutex::new(HashMap::new()));
 
     let app = Router::new()
@@ -74,7 +119,28 @@ async fn main() {
         .with_state(state);
 
     let listener = TcpListener::bind("0.0.0.0:8000").await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+
+    // signal을 받으면 새 연결을 받지 않고 upstream 요청 중인 요청이 끝나기를 기다린다. (최대 shutdown_timeout)
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(());
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            info!("Shutting down...");
+            let _ = shutdown_tx.send(());
+        })
+        .into_future();
+
+    tokio::select! {
+        result = server => {
+            if let Err(e) = result {
+                error!("Server error: {}", e);
+            }
+        }
+        _ = async {
+            let _ = shutdown_rx.changed().await;
+            tokio::time::sleep(shutdown_timeout).await;
+        } => warn!("Shutdown timed out after {:?}, dropping remaining requests", shutdown_timeout),
+    }
 }
 
 //-- request --
@@ -82,8 +82,9 @@ impl MigrationTrait for Migration {
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // chat이 room을 참조하므로 chat을 먼저 삭제
         manager
-            .drop_table(Table::drop().table(Users::Table).if_exists().to_owned())
+            .drop_table(Table::drop().table(Chat::Table).if_exists().to_owned())
             .await?;
 
         manager
@@ -91,7 +92,7 @@ impl MigrationTrait for Migration {
             .await?;
 
         manager
-            .drop_table(Table::drop().table(Chat::Table).if_exists().to_owned())
+            .drop_table(Table::drop().table(Users::Table).if_exists().to_owned())
             .await?;
 
         Ok(())
@@ -20,7 +20,7 @@ use axum::{
     Json,
 };
 
-use futures_util::stream::StreamExt;
+use futures_util::stream::{self, StreamExt};
 use serde_json::json;
 use serde::Deserialize;
 use tokio::sync::broadcast;
@@ -49,7 +49,15 @@ pub async fn subscribe(
                 .event("message")
                 .data(json!(message).to_string())),
             Err(e) => Err(e.to_string()),
-        });
+        })
+        // 서버 종료 시 마지막으로 shutdown 이벤트를 보내고 stream을 끝낸다.
+        // -- stream이 끝나야 graceful shutdown이 이 연결을 기다리지 않는다.
+        .take_until(async move { app_state.shutting_down().await })
+        .chain(stream::once(async {
+            Ok(Event::default()
+                .event("shutdown")
+                .data(json!({ "reason": "server shutting down" }).to_string()))
+        }));
 
     Sse::new(stream).keep_alive(KeepAlive::default())
 }
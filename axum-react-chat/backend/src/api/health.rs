@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+#[cfg(feature = "shuttle")]
+use shuttle_axum::axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+#[cfg(not(feature = "shuttle"))]
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use serde_json::json;
+use tracing::warn;
+
+use crate::api::state::AppState;
+
+// readiness 확인 시 DB ping 제한 시간
+const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+// liveness: 프로세스가 살아 있으면 항상 200
+pub async fn healthz() -> impl IntoResponse {
+    Json(json!({ "status": "ok" }))
+}
+
+// readiness: DB ping에 성공해야 200, 실패하면 503
+pub async fn readyz(State(state): State<AppState>) -> impl IntoResponse {
+    match tokio::time::timeout(PING_TIMEOUT, state.conn.ping()).await {
+        Ok(Ok(())) => (
+            StatusCode::OK,
+            Json(json!({ "status": "ok", "database": "ok" })),
+        ),
+        result => {
+            match result {
+                Ok(Err(e)) => warn!("Readiness check failed: {}", e),
+                _ => warn!("Readiness check timed out after {:?}", PING_TIMEOUT),
+            }
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({ "status": "unavailable", "database": "unavailable" })),
+            )
+        }
+    }
+}
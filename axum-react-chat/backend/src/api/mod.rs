@@ -1,4 +1,5 @@
 pub mod chat;
 pub mod chat_room;
+pub mod health;
 pub mod state;
 pub mod user;
\ No newline at end of file
@@ -2,10 +2,23 @@ use crate::entities::chat::Model as ChatModel;
 
 use sea_orm::DatabaseConnection;
 // use axum::extract::FromRef;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, watch};
 
 #[derive(Clone)]
 pub struct AppState {
     pub conn: DatabaseConnection,
     pub queue: broadcast::Sender<ChatModel>,
+    // 서버 종료 시 true로 바뀜 (SSE 구독을 끝내기 위해 사용)
+    pub shutdown: watch::Receiver<bool>,
+}
+
+impl AppState {
+    // 종료가 시작될 때까지 대기
+    // -- sender가 없으면 (shuttle처럼 종료를 런타임이 처리하는 경우) 끝나지 않는다.
+    pub async fn shutting_down(&self) {
+        let mut shutdown = self.shutdown.clone();
+        if shutdown.wait_for(|stopping| *stopping).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     api::{
         chat::{get_chat, send, subscribe},
         chat_room::{delete_room, get_room, post_room, put_room},
+        health::{healthz, readyz},
         state::AppState,
         user::{delete_user, get_user, post_user, put_user},
     },
@@ -23,7 +24,7 @@ use axum::{
 use migration::{Migrator, MigratorTrait};
 use sea_orm::{DatabaseConnection, SqlxPostgresConnector};
 use sqlx::PgPool;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, watch};
 use tower_http::{
     cors::{Any, CorsLayer},
     services::{ServeDir, ServeFile},
@@ -33,16 +34,20 @@ pub async fn create_app(pool: PgPool) -> Router {
     let conn = SqlxPostgresConnector::from_sqlx_postgres_pool(pool);
     Migrator::up(&conn, None).await.unwrap();
 
-    create_router(conn)
+    // shuttle은 종료를 런타임이 처리하므로 shutdown sender를 사용하지 않는다.
+    create_router(conn, watch::channel(false).1)
 }
 
-pub fn create_router(conn: DatabaseConnection) -> Router {
+pub fn create_router(conn: DatabaseConnection, shutdown: watch::Receiver<bool>) -> Router {
     let state = AppState {
         conn,
         queue: broadcast::channel(10).0,
+        shutdown,
     };
 
     Router::new()
+        .route("/healthz", get(healthz)) // liveness
+        .route("/readyz", get(readyz)) // readiness (DB ping)
         .nest(
             "/chat",
             Router::new()
@@ -9,10 +9,17 @@ use shuttle_axum::axum;
 #[cfg(not(feature = "shuttle"))]
 use axum;
 
+use std::{env, future::IntoFuture, time::Duration};
+
 use db::init_db;
 use migration::{Migrator, MigratorTrait};
+use tokio::{signal, sync::watch};
+use tracing::{info, warn};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
+// 종료 signal 후 처리 중인 요청을 기다리는 기본 시간 (SHUTDOWN_TIMEOUT_SECS로 변경)
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 10;
+
 #[tokio::main]
 async fn main() {
     dotenvy::dotenv().ok();
@@ -23,14 +30,75 @@ async fn main() {
         .try_init()
         .ok();
 
-    let db = init_db().await;
+    let db = match init_db().await {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::error!("Error connecting to database: {}", e);
+            std::process::exit(1);
+        }
+    };
     Migrator::up(&db, None).await.unwrap();
-    let app = app::create_router(db);
+
+    let shutdown_timeout = Duration::from_secs(
+        env::var("SHUTDOWN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_SECS),
+    );
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+    let app = app::create_router(db.clone(), shutdown_rx.clone());
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000")
         .await
         .unwrap();
-    axum::serve(listener, app).await.unwrap();
+
+    // signal을 받으면 SSE 구독자에게 shutdown 이벤트를 보내고 처리 중인 요청이 끝나기를 기다린다.
+    // -- shutdown_timeout이 지나면 남은 연결은 기다리지 않는다.
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            info!("Shutting down...");
+            let _ = shutdown_tx.send(true);
+        })
+        .into_future();
+
+    tokio::select! {
+        result = server => {
+            if let Err(e) = result {
+                tracing::error!("Server error: {}", e);
+            }
+        }
+        _ = async {
+            let _ = shutdown_rx.wait_for(|stopping| *stopping).await;
+            tokio::time::sleep(shutdown_timeout).await;
+        } => warn!("Shutdown timed out after {:?}, dropping remaining connections", shutdown_timeout),
+    }
+
+    if let Err(e) = db.close().await {
+        tracing::error!("Error closing database connections: {}", e);
+    }
+}
+
+// Ctrl+C (SIGINT) 또는 SIGTERM (docker stop)을 기다린다.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
 }
 
 // > cargo build --bin docker
@@ -1,6 +1,19 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20250624_091523_create_table;
+mod m20250701_000001_create_refresh_token;
+mod m20250702_000001_add_user_role;
+mod m20250703_000001_add_users_username_index;
+mod m20250704_000001_add_timestamps;
+mod m20250705_000001_create_audit_log;
+mod m20250706_000001_add_category_id;
+mod m20250707_000001_add_category_parent;
+mod m20250708_000001_add_product_search;
+mod m20250709_000001_add_product_currency;
+mod m20250709_000002_create_price_history;
+mod m20250710_000001_create_inventory;
+mod m20250711_000001_create_orders;
+mod m20250712_000001_create_product_image;
 
 pub struct Migrator;
 
@@ -9,6 +22,19 @@ impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         vec![
             Box::new(m20250624_091523_create_table::Migration),
+            Box::new(m20250701_000001_create_refresh_token::Migration),
+            Box::new(m20250702_000001_add_user_role::Migration),
+            Box::new(m20250703_000001_add_users_username_index::Migration),
+            Box::new(m20250704_000001_add_timestamps::Migration),
+            Box::new(m20250705_000001_create_audit_log::Migration),
+            Box::new(m20250706_000001_add_category_id::Migration),
+            Box::new(m20250707_000001_add_category_parent::Migration),
+            Box::new(m20250708_000001_add_product_search::Migration),
+            Box::new(m20250709_000001_add_product_currency::Migration),
+            Box::new(m20250709_000002_create_price_history::Migration),
+            Box::new(m20250710_000001_create_inventory::Migration),
+            Box::new(m20250711_000001_create_orders::Migration),
+            Box::new(m20250712_000001_create_product_image::Migration),
         ]
     }
 }
@@ -1,4 +1,4 @@
-use sea_orm_migration::{prelude::*, schema::*};
+use sea_orm_migration::prelude::*;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -108,14 +108,15 @@ impl MigrationTrait for Migration {
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // product가 category를 참조하므로 product를 먼저 삭제
         manager
-            .drop_table(Table::drop().table(Users::Table).if_exists().to_owned())
+            .drop_table(Table::drop().table(Product::Table).if_exists().to_owned())
             .await?;
         manager
             .drop_table(Table::drop().table(Category::Table).if_exists().to_owned())
             .await?;
         manager
-            .drop_table(Table::drop().table(Product::Table).if_exists().to_owned())
+            .drop_table(Table::drop().table(Users::Table).if_exists().to_owned())
             .await?;
 
         Ok(())
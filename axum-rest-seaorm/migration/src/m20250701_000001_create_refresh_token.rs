@@ -0,0 +1,132 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // refresh token은 원문 대신 SHA-256 해시만 저장한다.
+        // -- 같은 로그인에서 회전(rotation)된 토큰들은 family_id를 공유한다.
+        manager
+            .create_table(
+                Table::create()
+                    .table(RefreshToken::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RefreshToken::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::UserId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::TokenHash)
+                        .string()
+                        .not_null()
+                        .unique_key(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::FamilyId)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::ExpiresAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::RevokedAt)
+                        .timestamp_with_time_zone()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::ReplacedBy)
+                        .integer()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(RefreshToken::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_refresh_token_user")
+                            .from(RefreshToken::Table, RefreshToken::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_refresh_token_family_id")
+                    .table(RefreshToken::Table)
+                    .col(RefreshToken::FamilyId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // logout 등으로 폐기된 access token의 jti 목록
+        manager
+            .create_table(
+                Table::create()
+                    .table(RevokedToken::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RevokedToken::Jti)
+                        .string()
+                        .not_null()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(RevokedToken::ExpiresAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RevokedToken::Table).if_exists().to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(RefreshToken::Table).if_exists().to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum RefreshToken {
+    Table,
+    Id,
+    UserId,
+    TokenHash,
+    FamilyId,
+    ExpiresAt,
+    RevokedAt,
+    ReplacedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum RevokedToken {
+    Table,
+    Jti,
+    ExpiresAt,
+}
@@ -0,0 +1,133 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // product, category, users 테이블에 created_at / updated_at / deleted_at 컬럼 추가
+    // -- 기존 row의 created_at, updated_at은 migration 실행 시각으로 채워진다.
+    // -- SQLite는 ALTER 하나에 컬럼 하나만 추가할 수 있고, ADD COLUMN에
+    //    CURRENT_TIMESTAMP 같은 상수가 아닌 기본값을 쓸 수 없으므로
+    //    상수 기본값으로 추가한 뒤 기존 row를 현재 시각으로 갱신한다.
+    //    (새 row는 entity의 before_save에서 항상 값을 채운다)
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let sqlite = manager.get_database_backend() == DatabaseBackend::Sqlite;
+
+        for table in tables() {
+            for column in [Timestamps::CreatedAt, Timestamps::UpdatedAt] {
+                let mut def = ColumnDef::new(column);
+                def.timestamp_with_time_zone().not_null();
+                if sqlite {
+                    def.default(SQLITE_EPOCH);
+                } else {
+                    def.default(Expr::current_timestamp());
+                }
+
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(table.clone())
+                            .add_column(&mut def)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table.clone())
+                        .add_column(ColumnDef::new(Timestamps::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+
+            if sqlite {
+                manager
+                    .exec_stmt(
+                        Query::update()
+                            .table(table.clone())
+                            .value(Timestamps::CreatedAt, Expr::current_timestamp())
+                            .value(Timestamps::UpdatedAt, Expr::current_timestamp())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        // 삭제되지 않은 product 조회 (목록의 기본 필터)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_product_deleted_at")
+                    .table(Product::Table)
+                    .col(Timestamps::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_product_deleted_at")
+                    .table(Product::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in tables() {
+            for column in [Timestamps::CreatedAt, Timestamps::UpdatedAt, Timestamps::DeletedAt] {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(table.clone())
+                            .drop_column(column)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// SQLite에서 NOT NULL 컬럼을 추가할 때 사용하는 상수 기본값
+const SQLITE_EPOCH: &str = "1970-01-01 00:00:00";
+
+fn tables() -> [DynIden; 3] {
+    [
+        SeaRc::new(Product::Table),
+        SeaRc::new(Category::Table),
+        SeaRc::new(Users::Table),
+    ]
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(DeriveIden)]
+enum Timestamps {
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum Category {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+}
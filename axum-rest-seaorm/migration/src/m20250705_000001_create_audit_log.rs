@@ -0,0 +1,123 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 변경 이력 (누가, 언제, 어떤 entity를 어떻게 바꿨는지)
+        // -- 사용자가 삭제되어도 이력은 남아야 하므로 actor_id에 FK를 걸지 않는다.
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AuditLog::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuditLog::ActorId)
+                        .integer()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::ActorUsername)
+                        .string()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Action)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Entity)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::EntityId)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Before)
+                        .json()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::After)
+                        .json()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Diff)
+                        .json()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::RequestId)
+                        .string()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_entity")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::Entity)
+                    .col(AuditLog::EntityId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_actor_id")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::ActorId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_created_at")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    Id,
+    ActorId,
+    ActorUsername,
+    Action,
+    Entity,
+    EntityId,
+    Before,
+    After,
+    Diff,
+    RequestId,
+    CreatedAt,
+}
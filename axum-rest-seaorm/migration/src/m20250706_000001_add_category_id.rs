@@ -0,0 +1,338 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// category에 surrogate key(id)를 추가하고 name은 unique로 유지한다.
+// -- product.category는 계속 category.name을 참조하며, category 이름을 변경하면
+//    ON UPDATE CASCADE로 product에도 반영된다. (사용 중인 category 삭제는 RESTRICT)
+// -- SQLite는 primary key / foreign key를 ALTER로 변경할 수 없으므로 테이블을 새로 만들어
+//    데이터를 복사한다. (migration이 transaction 밖에서 실행되므로 foreign key 검사가
+//    깨지지 않는 순서로 진행: 새 테이블 생성 -> 복사 -> product, category 삭제 -> rename)
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            rebuild_sqlite(manager, true).await?;
+        } else {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name(FK_PRODUCT_CATEGORY)
+                        .table(Product::Table)
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .get_connection()
+                .execute_unprepared(r#"ALTER TABLE "category" DROP CONSTRAINT "category_pkey""#)
+                .await?;
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Category::Table)
+                        .add_column(ColumnDef::new(Category::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .create_index(
+                    Index::create()
+                        .name(IDX_CATEGORY_NAME)
+                        .table(Category::Table)
+                        .col(Category::Name)
+                        .unique()
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .create_foreign_key(product_category_fk(Category::Table, true).to_owned())
+                .await?;
+        }
+
+        // category별 product 조회/개수 집계와 cascade 갱신에 사용
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_PRODUCT_CATEGORY)
+                    .table(Product::Table)
+                    .col(Product::Category)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            // 테이블을 다시 만들면 idx_product_category도 함께 삭제된다.
+            return rebuild_sqlite(manager, false).await;
+        }
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_PRODUCT_CATEGORY)
+                    .table(Product::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_PRODUCT_CATEGORY)
+                    .table(Product::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Category::Table)
+                    .drop_column(Category::Id)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_CATEGORY_NAME)
+                    .table(Category::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(r#"ALTER TABLE "category" ADD PRIMARY KEY ("name")"#)
+            .await?;
+        manager
+            .create_foreign_key(product_category_fk(Category::Table, false).to_owned())
+            .await
+    }
+}
+
+const FK_PRODUCT_CATEGORY: &str = "fk_product_category";
+const IDX_CATEGORY_NAME: &str = "idx_category_name";
+const IDX_PRODUCT_CATEGORY: &str = "idx_product_category";
+const IDX_PRODUCT_DELETED_AT: &str = "idx_product_deleted_at";
+
+// product.category -> category.name
+// -- cascade가 false이면 이전 migration과 같은 제약 조건 (ON UPDATE/DELETE 없음)
+fn product_category_fk<T: IntoIden + 'static>(category_table: T, cascade: bool) -> ForeignKeyCreateStatement {
+    let mut fk = ForeignKey::create();
+    fk.name(FK_PRODUCT_CATEGORY)
+        .from(Product::Table, Product::Category)
+        .to(category_table, Category::Name);
+    if cascade {
+        fk.on_update(ForeignKeyAction::Cascade)
+            .on_delete(ForeignKeyAction::Restrict);
+    }
+    fk
+}
+
+fn timestamp_columns() -> [ColumnDef; 3] {
+    [
+        ColumnDef::new(Timestamps::CreatedAt)
+            .timestamp_with_time_zone()
+            .not_null()
+            .default(Expr::current_timestamp())
+            .to_owned(),
+        ColumnDef::new(Timestamps::UpdatedAt)
+            .timestamp_with_time_zone()
+            .not_null()
+            .default(Expr::current_timestamp())
+            .to_owned(),
+        ColumnDef::new(Timestamps::DeletedAt)
+            .timestamp_with_time_zone()
+            .null()
+            .to_owned(),
+    ]
+}
+
+// 복사할 컬럼 목록 (created_at, updated_at, deleted_at 포함)
+fn with_timestamps<const N: usize>(columns: [DynIden; N]) -> Vec<DynIden> {
+    let mut columns = columns.to_vec();
+    columns.extend([
+        Timestamps::CreatedAt.into_iden(),
+        Timestamps::UpdatedAt.into_iden(),
+        Timestamps::DeletedAt.into_iden(),
+    ]);
+    columns
+}
+
+// SQLite: category, product 테이블을 새 스키마로 다시 만든다.
+// -- surrogate_id가 true이면 up (id 추가), false이면 down (name을 primary key로 복원)
+async fn rebuild_sqlite(manager: &SchemaManager<'_>, surrogate_id: bool) -> Result<(), DbErr> {
+    let mut category = Table::create();
+    category.table(CategoryNew::Table);
+    if surrogate_id {
+        category
+            .col(ColumnDef::new(Category::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+            )
+            .col(ColumnDef::new(Category::Name)
+                .string()
+                .not_null(),
+            );
+    } else {
+        category.col(ColumnDef::new(Category::Name)
+            .string()
+            .unique_key()
+            .not_null()
+            .primary_key(),
+        );
+    }
+    for mut column in timestamp_columns() {
+        category.col(&mut column);
+    }
+    manager.create_table(category.to_owned()).await?;
+
+    if surrogate_id {
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_CATEGORY_NAME)
+                    .table(CategoryNew::Table)
+                    .col(Category::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+    }
+
+    let category_columns = with_timestamps([Category::Name.into_iden()]);
+    manager
+        .exec_stmt(
+            Query::insert()
+                .into_table(CategoryNew::Table)
+                .columns(category_columns.clone())
+                .select_from(
+                    Query::select()
+                        .columns(category_columns)
+                        .from(Category::Table)
+                        .order_by(Category::Name, Order::Asc)
+                        .to_owned(),
+                )
+                .map_err(|err| DbErr::Custom(err.to_string()))?
+                .to_owned(),
+        )
+        .await?;
+
+    let mut product = Table::create();
+    product
+        .table(ProductNew::Table)
+        .col(ColumnDef::new(Product::Id)
+            .integer()
+            .not_null()
+            .auto_increment()
+            .primary_key(),
+        )
+        .col(ColumnDef::new(Product::Title)
+            .string()
+            .not_null(),
+        )
+        .col(ColumnDef::new(Product::Price)
+            .integer()
+            .not_null(),
+        )
+        .col(ColumnDef::new(Product::Category)
+            .string()
+            .not_null(),
+        );
+    for mut column in timestamp_columns() {
+        product.col(&mut column);
+    }
+    // category_new를 category로 rename하면 이 foreign key의 참조 테이블 이름도 함께 바뀐다.
+    let mut fk = product_category_fk(CategoryNew::Table, surrogate_id);
+    fk.from_tbl(ProductNew::Table);
+    product.foreign_key(&mut fk);
+    manager.create_table(product.to_owned()).await?;
+
+    let product_columns = with_timestamps([
+        Product::Id.into_iden(),
+        Product::Title.into_iden(),
+        Product::Price.into_iden(),
+        Product::Category.into_iden(),
+    ]);
+    manager
+        .exec_stmt(
+            Query::insert()
+                .into_table(ProductNew::Table)
+                .columns(product_columns.clone())
+                .select_from(
+                    Query::select()
+                        .columns(product_columns)
+                        .from(Product::Table)
+                        .to_owned(),
+                )
+                .map_err(|err| DbErr::Custom(err.to_string()))?
+                .to_owned(),
+        )
+        .await?;
+
+    // product를 먼저 삭제해야 category를 참조하는 row가 남지 않는다.
+    manager
+        .drop_table(Table::drop().table(Product::Table).to_owned())
+        .await?;
+    manager
+        .drop_table(Table::drop().table(Category::Table).to_owned())
+        .await?;
+    manager
+        .rename_table(Table::rename().table(CategoryNew::Table, Category::Table).to_owned())
+        .await?;
+    manager
+        .rename_table(Table::rename().table(ProductNew::Table, Product::Table).to_owned())
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name(IDX_PRODUCT_DELETED_AT)
+                .table(Product::Table)
+                .col(Timestamps::DeletedAt)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum Category {
+    Table,
+    Id,
+    Name,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(DeriveIden)]
+enum Timestamps {
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum CategoryNew {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    Id,
+    Title,
+    Price,
+    Category,
+}
+
+#[derive(DeriveIden)]
+enum ProductNew {
+    Table,
+}
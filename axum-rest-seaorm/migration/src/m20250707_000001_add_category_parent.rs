@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// category 계층 구조: parent_id가 NULL이면 최상위 category
+// -- 하위 category가 있는 category는 삭제할 수 없다. (ON DELETE RESTRICT)
+// -- 순환 참조(자기 자신이나 하위 category 아래로 이동)는 API에서 막는다.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            // SQLite는 기존 테이블에 foreign key 제약 조건을 추가할 수 없지만
+            // ADD COLUMN에 REFERENCES를 함께 쓰는 것은 허용된다. (sea-query는 지원하지 않음)
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"ALTER TABLE "category" ADD COLUMN "parent_id" integer NULL REFERENCES "category" ("id") ON DELETE RESTRICT"#,
+                )
+                .await?;
+        } else {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Category::Table)
+                        .add_column(ColumnDef::new(Category::ParentId)
+                            .integer()
+                            .null(),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name(FK_CATEGORY_PARENT)
+                        .from(Category::Table, Category::ParentId)
+                        .to(Category::Table, Category::Id)
+                        .on_delete(ForeignKeyAction::Restrict)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 하위 category 조회 (재귀 CTE의 join 조건)
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_CATEGORY_PARENT_ID)
+                    .table(Category::Table)
+                    .col(Category::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_CATEGORY_PARENT_ID)
+                    .table(Category::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name(FK_CATEGORY_PARENT)
+                        .table(Category::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Category::Table)
+                    .drop_column(Category::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+const FK_CATEGORY_PARENT: &str = "fk_category_parent";
+const IDX_CATEGORY_PARENT_ID: &str = "idx_category_parent_id";
+
+#[derive(DeriveIden)]
+enum Category {
+    Table,
+    Id,
+    ParentId,
+}
@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// product 전문 검색 (GET /api/v1/products/search)
+// -- Postgres: title로 계산되는 tsvector generated column + GIN index
+//    언어별 형태소 분석 없이 단어 단위로 검색하도록 'simple' 설정을 사용한다. (한글/영문 혼용)
+//    한글 단어가 분리되려면 DB encoding이 UTF8이어야 한다.
+// -- SQLite는 tsvector가 없으므로 API에서 LIKE 검색으로 대체한다. (변경 없음)
+// -- search_vector는 DB에서만 사용하므로 entity에는 추가하지 않는다.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(ColumnDef::new(Product::SearchVector)
+                        .custom(Alias::new("tsvector"))
+                        .extra("GENERATED ALWAYS AS (to_tsvector('simple', coalesce(\"title\", ''))) STORED"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_PRODUCT_SEARCH_VECTOR)
+                    .table(Product::Table)
+                    .col(Product::SearchVector)
+                    .full_text()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            return Ok(());
+        }
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_PRODUCT_SEARCH_VECTOR)
+                    .table(Product::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_column(Product::SearchVector)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+const IDX_PRODUCT_SEARCH_VECTOR: &str = "idx_product_search_vector";
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    SearchVector,
+}
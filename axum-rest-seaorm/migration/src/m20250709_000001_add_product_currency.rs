@@ -0,0 +1,206 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// product.price를 정수에서 decimal 금액으로 변경하고 ISO 4217 통화 코드(currency)를 추가한다.
+// -- 기존 price는 모두 원화이므로 currency 기본값은 KRW
+// -- Postgres: numeric(19, 4)
+// -- SQLite: decimal 타입이 없고 sea-orm이 f64로 읽으므로 real로 저장한다.
+//    컬럼 타입을 ALTER로 변경할 수 없어 product 테이블을 새로 만들어 데이터를 복사한다.
+// -- down은 소수점 이하를 반올림해서 정수로 되돌린다. (currency는 삭제)
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            return rebuild_sqlite(manager, true).await;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .modify_column(ColumnDef::new(Product::Price)
+                        .decimal_len(19, 4)
+                        .not_null(),
+                    )
+                    .add_column(currency_column())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Sqlite {
+            return rebuild_sqlite(manager, false).await;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .modify_column(ColumnDef::new(Product::Price)
+                        .integer()
+                        .not_null(),
+                    )
+                    .drop_column(Product::Currency)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+const DEFAULT_CURRENCY: &str = "KRW";
+const FK_PRODUCT_CATEGORY: &str = "fk_product_category";
+const IDX_PRODUCT_CATEGORY: &str = "idx_product_category";
+const IDX_PRODUCT_DELETED_AT: &str = "idx_product_deleted_at";
+
+fn currency_column() -> ColumnDef {
+    ColumnDef::new(Product::Currency)
+        .string_len(3)
+        .not_null()
+        .default(DEFAULT_CURRENCY)
+        .to_owned()
+}
+
+// SQLite: product 테이블을 새 price 타입으로 다시 만든다.
+// -- decimal이 true이면 up (real + currency), false이면 down (integer)
+// -- product를 참조하는 테이블이 없으므로 product만 다시 만들면 된다. (price_history는 다음 migration)
+async fn rebuild_sqlite(manager: &SchemaManager<'_>, decimal: bool) -> Result<(), DbErr> {
+    let mut product = Table::create();
+    product
+        .table(ProductNew::Table)
+        .col(ColumnDef::new(Product::Id)
+            .integer()
+            .not_null()
+            .auto_increment()
+            .primary_key(),
+        )
+        .col(ColumnDef::new(Product::Title)
+            .string()
+            .not_null(),
+        );
+    if decimal {
+        product
+            .col(ColumnDef::new(Product::Price)
+                .custom(Alias::new("real"))
+                .not_null(),
+            )
+            .col(currency_column());
+    } else {
+        product.col(ColumnDef::new(Product::Price)
+            .integer()
+            .not_null(),
+        );
+    }
+    product
+        .col(ColumnDef::new(Product::Category)
+            .string()
+            .not_null(),
+        )
+        .col(ColumnDef::new(Product::CreatedAt)
+            .timestamp_with_time_zone()
+            .not_null()
+            .default(Expr::current_timestamp()),
+        )
+        .col(ColumnDef::new(Product::UpdatedAt)
+            .timestamp_with_time_zone()
+            .not_null()
+            .default(Expr::current_timestamp()),
+        )
+        .col(ColumnDef::new(Product::DeletedAt)
+            .timestamp_with_time_zone()
+            .null(),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name(FK_PRODUCT_CATEGORY)
+                .from(ProductNew::Table, Product::Category)
+                .to(Category::Table, Category::Name)
+                .on_update(ForeignKeyAction::Cascade)
+                .on_delete(ForeignKeyAction::Restrict),
+        );
+    manager.create_table(product.to_owned()).await?;
+
+    // price는 마지막 컬럼으로 복사 (down에서는 반올림한 정수)
+    let columns = || [
+        Product::Id,
+        Product::Title,
+        Product::Category,
+        Product::CreatedAt,
+        Product::UpdatedAt,
+        Product::DeletedAt,
+    ];
+    let price = if decimal {
+        Expr::col(Product::Price).into()
+    } else {
+        Expr::cust(r#"CAST(round("price") AS integer)"#)
+    };
+    manager
+        .exec_stmt(
+            Query::insert()
+                .into_table(ProductNew::Table)
+                .columns(columns().into_iter().chain([Product::Price]))
+                .select_from(
+                    Query::select()
+                        .columns(columns())
+                        .expr(price)
+                        .from(Product::Table)
+                        .to_owned(),
+                )
+                .map_err(|err| DbErr::Custom(err.to_string()))?
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .drop_table(Table::drop().table(Product::Table).to_owned())
+        .await?;
+    manager
+        .rename_table(Table::rename().table(ProductNew::Table, Product::Table).to_owned())
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name(IDX_PRODUCT_DELETED_AT)
+                .table(Product::Table)
+                .col(Product::DeletedAt)
+                .to_owned(),
+        )
+        .await?;
+    manager
+        .create_index(
+            Index::create()
+                .name(IDX_PRODUCT_CATEGORY)
+                .table(Product::Table)
+                .col(Product::Category)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum Category {
+    Table,
+    Name,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    Id,
+    Title,
+    Price,
+    Currency,
+    Category,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+enum ProductNew {
+    Table,
+}
@@ -0,0 +1,107 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// product 가격 변경 이력 (PUT/PATCH로 price 또는 currency가 바뀔 때마다 기록)
+// -- product는 soft delete되므로 이력도 남아 있지만, row가 삭제되면 함께 삭제한다.
+// -- audit_log와 마찬가지로 사용자가 삭제되어도 이력은 남아야 하므로 changed_by에 FK를 걸지 않는다.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let sqlite = manager.get_database_backend() == DatabaseBackend::Sqlite;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PriceHistory::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PriceHistory::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(PriceHistory::ProductId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(price_column(PriceHistory::OldPrice, sqlite))
+                    .col(ColumnDef::new(PriceHistory::OldCurrency)
+                        .string_len(3)
+                        .not_null(),
+                    )
+                    .col(price_column(PriceHistory::NewPrice, sqlite))
+                    .col(ColumnDef::new(PriceHistory::NewCurrency)
+                        .string_len(3)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(PriceHistory::ChangedBy)
+                        .integer()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(PriceHistory::ChangedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_price_history_product")
+                            .from(PriceHistory::Table, PriceHistory::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_price_history_product_id")
+                    .table(PriceHistory::Table)
+                    .col(PriceHistory::ProductId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PriceHistory::Table).to_owned())
+            .await
+    }
+}
+
+// product.price와 같은 타입 (Postgres: numeric(19, 4), SQLite: real)
+fn price_column(column: PriceHistory, sqlite: bool) -> ColumnDef {
+    let mut def = ColumnDef::new(column);
+    if sqlite {
+        def.custom(Alias::new("real"));
+    } else {
+        def.decimal_len(19, 4);
+    }
+    def.not_null().to_owned()
+}
+
+#[derive(DeriveIden)]
+enum PriceHistory {
+    Table,
+    Id,
+    ProductId,
+    OldPrice,
+    OldCurrency,
+    NewPrice,
+    NewCurrency,
+    ChangedBy,
+    ChangedAt,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    Id,
+}
@@ -0,0 +1,241 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 재고 관리
+// -- inventory: product별 현재 재고 (quantity: 보유 수량, reserved: 예약된 수량)
+//    판매 가능 수량은 quantity - reserved이며, inventory row가 없는 product의 재고는 0
+// -- inventory_adjustment: 재고 변경 이력 (입고, 판매, 파손 등 reason code와 함께 기록)
+// -- reservation: TTL이 있는 재고 예약 (pending -> confirmed | cancelled | expired)
+// -- 사용자가 삭제되어도 이력은 남아야 하므로 created_by, user_id에 FK를 걸지 않는다.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Inventory::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Inventory::ProductId)
+                        .integer()
+                        .not_null()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(Inventory::Quantity)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                    )
+                    .col(ColumnDef::new(Inventory::Reserved)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                    )
+                    .col(ColumnDef::new(Inventory::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_inventory_product")
+                            .from(Inventory::Table, Inventory::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(InventoryAdjustment::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(InventoryAdjustment::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::ProductId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::Delta)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::Reason)
+                        .string_len(20)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::Note)
+                        .string()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::QuantityAfter)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::CreatedBy)
+                        .integer()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(InventoryAdjustment::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_inventory_adjustment_product")
+                            .from(InventoryAdjustment::Table, InventoryAdjustment::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_inventory_adjustment_product_id")
+                    .table(InventoryAdjustment::Table)
+                    .col(InventoryAdjustment::ProductId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reservation::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Reservation::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(Reservation::ProductId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Reservation::UserId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Reservation::Quantity)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Reservation::Status)
+                        .string_len(20)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Reservation::ExpiresAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Reservation::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(Reservation::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reservation_product")
+                            .from(Reservation::Table, Reservation::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 만료 처리: status = 'pending' AND expires_at <= now
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reservation_status_expires_at")
+                    .table(Reservation::Table)
+                    .col(Reservation::Status)
+                    .col(Reservation::ExpiresAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reservation_product_id")
+                    .table(Reservation::Table)
+                    .col(Reservation::ProductId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Reservation::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(InventoryAdjustment::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Inventory::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Inventory {
+    Table,
+    ProductId,
+    Quantity,
+    Reserved,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum InventoryAdjustment {
+    Table,
+    Id,
+    ProductId,
+    Delta,
+    Reason,
+    Note,
+    QuantityAfter,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Reservation {
+    Table,
+    Id,
+    ProductId,
+    UserId,
+    Quantity,
+    Status,
+    ExpiresAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    Id,
+}
@@ -0,0 +1,189 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 주문
+// -- orders: 주문 상태 (pending -> paid -> shipped, pending | paid -> cancelled)와 합계 금액
+// -- order_items: 주문 시점의 product title, 가격을 복사해 두므로 이후 product가 바뀌어도 주문 내역은 그대로다.
+// -- 주문 내역은 남아야 하므로 product는 삭제할 수 없게 하고 (product는 soft delete),
+//    reservation과 마찬가지로 user_id에는 FK를 걸지 않는다.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let sqlite = manager.get_database_backend() == DatabaseBackend::Sqlite;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Orders::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Orders::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(Orders::UserId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Orders::Status)
+                        .string_len(20)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(Orders::Currency)
+                        .string_len(3)
+                        .not_null(),
+                    )
+                    .col(amount_column(Orders::Total, sqlite))
+                    .col(ColumnDef::new(Orders::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(Orders::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(Orders::PaidAt)
+                        .timestamp_with_time_zone()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(Orders::ShippedAt)
+                        .timestamp_with_time_zone()
+                        .null(),
+                    )
+                    .col(ColumnDef::new(Orders::CancelledAt)
+                        .timestamp_with_time_zone()
+                        .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_user_id")
+                    .table(Orders::Table)
+                    .col(Orders::UserId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderItems::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(OrderItems::Id)
+                        .big_integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(OrderItems::OrderId)
+                        .big_integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(OrderItems::ProductId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(OrderItems::Title)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(amount_column(OrderItems::UnitPrice, sqlite))
+                    .col(ColumnDef::new(OrderItems::Quantity)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(amount_column(OrderItems::LineTotal, sqlite))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_order_items_order")
+                            .from(OrderItems::Table, OrderItems::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_order_items_product")
+                            .from(OrderItems::Table, OrderItems::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_order_items_order_id")
+                    .table(OrderItems::Table)
+                    .col(OrderItems::OrderId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrderItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Orders::Table).to_owned())
+            .await
+    }
+}
+
+// product.price와 같은 타입 (Postgres: numeric(19, 4), SQLite: real)
+fn amount_column<T: IntoIden>(column: T, sqlite: bool) -> ColumnDef {
+    let mut def = ColumnDef::new(column);
+    if sqlite {
+        def.custom(Alias::new("real"));
+    } else {
+        def.decimal_len(19, 4);
+    }
+    def.not_null().to_owned()
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    Id,
+    UserId,
+    Status,
+    Currency,
+    Total,
+    CreatedAt,
+    UpdatedAt,
+    PaidAt,
+    ShippedAt,
+    CancelledAt,
+}
+
+#[derive(DeriveIden)]
+enum OrderItems {
+    Table,
+    Id,
+    OrderId,
+    ProductId,
+    Title,
+    UnitPrice,
+    Quantity,
+    LineTotal,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    Id,
+}
@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// product 이미지
+// -- 파일 자체는 storage (로컬 파일 시스템 등)에 저장하고, 이 테이블에는 storage key와 메타데이터만 둔다.
+// -- checksum (원본의 SHA-256)은 이미지 응답의 ETag로 사용한다.
+// -- product를 hard delete하면 이미지 행도 함께 지운다. (storage 파일은 DELETE API에서 지운다)
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductImage::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ProductImage::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                    )
+                    .col(ColumnDef::new(ProductImage::ProductId)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::FileName)
+                        .string_len(255)
+                        .null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::ContentType)
+                        .string_len(50)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::ByteSize)
+                        .big_integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::Width)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::Height)
+                        .integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::Checksum)
+                        .string_len(64)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::StorageKey)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::ThumbnailKey)
+                        .string()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::ThumbnailContentType)
+                        .string_len(50)
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::ThumbnailByteSize)
+                        .big_integer()
+                        .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductImage::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null()
+                        .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_product_image_product")
+                            .from(ProductImage::Table, ProductImage::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_product_image_product_id")
+                    .table(ProductImage::Table)
+                    .col(ProductImage::ProductId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductImage::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductImage {
+    Table,
+    Id,
+    ProductId,
+    FileName,
+    ContentType,
+    ByteSize,
+    Width,
+    Height,
+    Checksum,
+    StorageKey,
+    ThumbnailKey,
+    ThumbnailContentType,
+    ThumbnailByteSize,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    Id,
+}
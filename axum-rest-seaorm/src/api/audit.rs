@@ -0,0 +1,81 @@
+use axum::extract::{Query, State};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::entities::audit_log::{Column, Entity, Model};
+use crate::utils::app_error::AppError;
+use crate::utils::pagination::{ListQuery, ListSpec, Page, PaginationParams};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQueryParams {
+    /// 변경한 사용자 ID
+    actor_id: Option<i32>,
+    /// 변경한 사용자 이름 (정확히 일치)
+    actor: Option<String>,
+    /// 대상 entity (users, product, category)
+    entity: Option<String>,
+    /// 대상 entity의 primary key
+    entity_id: Option<String>,
+    /// create, update, delete, restore
+    action: Option<String>,
+}
+
+const LIST_SPEC: ListSpec<Column> = ListSpec {
+    sortable: &[Column::Id, Column::CreatedAt],
+    filterable: &[Column::Id, Column::CreatedAt],
+    default_sort: &[],
+    cursor: Column::Id,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        AuditQueryParams,
+        PaginationParams,
+        ("created_at_gte" = Option<String>, Query, description = "Changes at or after this time (RFC 3339)"),
+        ("created_at_lte" = Option<String>, Query, description = "Changes at or before this time (RFC 3339)")
+    ),
+    responses(
+        (status = 200, description = "Page of audit log entries (sortable by id, created_at)", body = AuditLogPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching entries")
+            )
+        ),
+        (status = 400, description = "Invalid pagination, sort or filter parameters", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Audit"
+)]
+pub async fn list_audit_handler(
+    State(conn): State<DatabaseConnection>,
+    Query(params): Query<AuditQueryParams>,
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    let mut condition = Condition::all();
+
+    if let Some(actor_id) = params.actor_id {
+        condition = condition.add(Column::ActorId.eq(actor_id));
+    }
+    if let Some(actor) = params.actor {
+        condition = condition.add(Column::ActorUsername.eq(actor));
+    }
+    if let Some(entity) = params.entity {
+        condition = condition.add(Column::Entity.eq(entity));
+    }
+    if let Some(entity_id) = params.entity_id {
+        condition = condition.add(Column::EntityId.eq(entity_id));
+    }
+    if let Some(action) = params.action {
+        condition = condition.add(Column::Action.eq(action));
+    }
+
+    list.fetch(&conn, Entity::find().filter(condition), &LIST_SPEC).await
+}
@@ -1,15 +1,21 @@
-use crate::entities::users::{Column, Entity as UsersEntity};
+use crate::config::Config;
+use crate::entities::revoked_token;
+use crate::entities::users::{Column, Entity as UsersEntity, Model as UsersModel};
 use crate::utils::app_error::AppError;
-use crate::utils::hash::verify_password;
-use crate::utils::jwt::create_token;
+use crate::utils::hash::PasswordHasher;
+use crate::utils::jwt::JwtKeys;
+use crate::utils::refresh_token;
 use axum::{
     extract::State,
-    http::StatusCode,
+    http::HeaderMap,
     Json,
 };
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::{TimeZone, Utc};
+use sea_orm::{
+    sea_query::OnConflict, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+};
 use serde::{Deserialize, Serialize};
-use tracing::error;
+use std::sync::Arc;
 use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -18,33 +24,153 @@ pub struct LoginRequest {
     pub password: String,
 }
 
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RefreshRequest {
+    #[schema(example = "3f2b9c0e7d4a4e51a8c6f1d2b3e4a5c69b8a7f6e5d4c4b3a9f8e7d6c5b4a3f2e")]
+    pub refresh_token: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: String,
+    #[schema(example = "Bearer")]
+    pub token_type: String,
+    // access token 만료까지 남은 시간(초)
+    #[schema(example = 3600)]
+    pub expires_in: i64,
+}
+
+impl TokenResponse {
+    fn new(keys: &JwtKeys, user: UsersModel, refresh_token: String) -> Result<Self, AppError> {
+        Ok(Self {
+            access_token: keys.create_token(user.id, user.username, vec![user.role])?,
+            refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: keys.access_token_ttl().num_seconds(),
+        })
+    }
+}
+
 #[utoipa::path(
     post,
-    path = "/auth/login",
+    path = "/api/v1/auth/login",
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = String),
+        (status = 200, description = "Login successful", body = TokenResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse)
     ),
     tag = "Auth"
 )]
 pub async fn login_handler(
     State(db): State<DatabaseConnection>,
+    State(config): State<Arc<Config>>,
+    State(keys): State<JwtKeys>,
+    State(hasher): State<PasswordHasher>,
     Json(user_request): Json<LoginRequest>,
-) -> Result<String, AppError> {
+) -> Result<Json<TokenResponse>, AppError> {
     let user = UsersEntity::find()
         .filter(Column::Username.eq(user_request.username))
         .one(&db)
-        .await
-        .map_err(|err| {
-            error!("Error finding user: {:?}", err);
-            AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Error finding user")
-        })?
-        .ok_or_else(|| AppError::new(StatusCode::BAD_REQUEST, "User not found"))?;
-
-    if !verify_password(&user_request.password, &user.password)? {
-        return Err(AppError::new(StatusCode::UNAUTHORIZED, "Invalid password"));
+        .await?
+        .ok_or_else(|| AppError::BadRequest("User not found".into()))?;
+
+    if !hasher.verify(user_request.password, user.password.clone()).await? {
+        return Err(AppError::Unauthorized("Invalid password".into()));
+    }
+
+    let (refresh_token, _) = refresh_token::issue(&db, &config.auth, user.id, None).await?;
+    Ok(Json(TokenResponse::new(&keys, user, refresh_token)?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/refresh",
+    request_body = RefreshRequest,
+    responses(
+        (status = 200, description = "Tokens refreshed", body = TokenResponse),
+        (status = 401, description = "Invalid, expired, revoked or reused refresh token", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn refresh_handler(
+    State(db): State<DatabaseConnection>,
+    State(config): State<Arc<Config>>,
+    State(keys): State<JwtKeys>,
+    Json(request): Json<RefreshRequest>,
+) -> Result<Json<TokenResponse>, AppError> {
+    let (refresh_token, stored) = refresh_token::rotate(&db, &config.auth, &request.refresh_token).await?;
+
+    let user = UsersEntity::find_by_id(stored.user_id)
+        .one(&db)
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("User not found".into()))?;
+
+    Ok(Json(TokenResponse::new(&keys, user, refresh_token)?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout",
+    security(
+        (),
+        ("bearer_auth" = [])
+    ),
+    request_body = RefreshRequest,
+    responses(
+        (status = 200, description = "Logged out", body = String),
+        (status = 401, description = "Invalid refresh token", body = ErrorResponse),
+        (status = 403, description = "Access token and refresh token belong to different users", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn logout_handler(
+    State(db): State<DatabaseConnection>,
+    State(keys): State<JwtKeys>,
+    headers: HeaderMap,
+    Json(request): Json<RefreshRequest>,
+) -> Result<Json<&'static str>, AppError> {
+    let stored = refresh_token::find(&db, &request.refresh_token).await?;
+
+    // access token이 함께 전달되면 남은 유효 시간 동안 사용하지 못하도록 jti를 폐기 목록에 등록
+    // -- 다른 사용자의 access token은 폐기하지 않는다. (이 경우 refresh token도 폐기하지 않고 403)
+    let claims = headers
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| keys.validate_token(value).ok());
+    if claims.as_ref().is_some_and(|claims| claims.user_id != stored.user_id) {
+        return Err(AppError::Forbidden("Access token does not belong to the refresh token's user".into()));
+    }
+
+    refresh_token::revoke_family(&db, &stored.family_id).await?;
+
+    if let Some(claims) = claims {
+        let expires_at = Utc
+            .timestamp_opt(claims.exp as i64, 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+
+        let revoked = revoked_token::ActiveModel {
+            jti: ActiveValue::Set(claims.jti),
+            expires_at: ActiveValue::Set(expires_at.fixed_offset()),
+        };
+
+        revoked_token::Entity::insert(revoked)
+            .on_conflict(
+                OnConflict::column(revoked_token::Column::Jti)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(&db)
+            .await?;
     }
 
-    Ok(create_token(user.username.clone())?)
+    // 만료된 jti는 더 이상 검사할 필요가 없으므로 정리
+    revoked_token::Entity::delete_many()
+        .filter(revoked_token::Column::ExpiresAt.lt(Utc::now().fixed_offset()))
+        .exec(&db)
+        .await?;
+
+    Ok(Json("Logged out"))
 }
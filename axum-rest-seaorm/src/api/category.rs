@@ -1,17 +1,111 @@
 use std::collections::HashMap;
 use axum::{
-    extract::{Query, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, HeaderName, StatusCode},
     Json,
 };
+use chrono::Utc;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
-    ModelTrait, QueryFilter,
+    sea_query::{
+        self, BinOper, CommonTableExpression, Expr, SimpleExpr, SubQueryStatement, UnionType,
+        WithClause,
+    },
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DeriveIden, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    Select, TransactionTrait,
 };
+use serde::{Deserialize, Serialize};
 
+use crate::api;
 use crate::entities::category::{ActiveModel, Column, Entity, Model};
-use crate::utils::app_error::AppError;
-use utoipa::ToSchema;
+use crate::entities::product;
+use crate::utils::app_error::{AppError, Dependent};
+use crate::utils::audit::AuditEntry;
+use crate::utils::auth_user::AuthUser;
+use crate::utils::pagination::{ListQuery, ListSpec, Page, PaginationParams};
+use crate::utils::validated_json::{not_blank, require_fields, ValidatedJson};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+// audit_log.entity 값
+pub const AUDIT_ENTITY: &str = "category";
+
+// 사용 중인 category 삭제 시 409 응답에 포함할 최대 product 수
+const MAX_DEPENDENTS: u64 = 20;
+
+// name은 unique (id는 내부용 surrogate key)
+fn find_by_name(name: &str) -> Select<Entity> {
+    Entity::find().filter(Column::Name.eq(name))
+}
+
+// 재귀 CTE 이름
+#[derive(DeriveIden)]
+enum Tree {
+    Table,
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Descendants,
+    Ancestors,
+}
+
+// start 조건에 맞는 category부터 parent_id를 따라 내려가거나(Descendants) 올라가며(Ancestors)
+// 찾은 category들의 column 값을 반환하는 subquery (start에 해당하는 category 포함)
+//
+// WITH RECURSIVE tree (id, parent_id, name) AS (
+//     SELECT id, parent_id, name FROM category WHERE <start>
+//     UNION
+//     SELECT category.id, category.parent_id, category.name FROM category
+//     JOIN tree ON category.parent_id = tree.id   -- Descendants
+//                  category.id = tree.parent_id   -- Ancestors
+// ) SELECT <column> FROM tree
+// -- UNION(중복 제거)을 사용하므로 데이터에 순환이 있더라도 재귀가 끝난다.
+fn tree_subquery(start: SimpleExpr, direction: Direction, column: Column) -> SimpleExpr {
+    let columns = [Column::Id, Column::ParentId, Column::Name];
+    let (child, parent) = match direction {
+        Direction::Descendants => (Column::ParentId, Column::Id),
+        Direction::Ancestors => (Column::Id, Column::ParentId),
+    };
+
+    let step = sea_query::Query::select()
+        .columns(columns.map(|column| (Entity, column)))
+        .from(Entity)
+        .inner_join(Tree::Table, Expr::col((Entity, child)).equals((Tree::Table, parent)))
+        .to_owned();
+    let base = sea_query::Query::select()
+        .columns(columns)
+        .from(Entity)
+        .and_where(start)
+        .union(UnionType::Distinct, step)
+        .to_owned();
+
+    let cte = CommonTableExpression::new()
+        .query(base)
+        .columns(columns)
+        .table_name(Tree::Table)
+        .to_owned();
+    let query = WithClause::new()
+        .recursive(true)
+        .cte(cte)
+        .to_owned()
+        .query(sea_query::Query::select().column(column).from(Tree::Table).to_owned());
+
+    SimpleExpr::SubQuery(None, Box::new(SubQueryStatement::WithStatement(query)))
+}
+
+// 이름이 name인 category와 그 하위 category 전체의 이름 (product 목록의 include_descendants 필터)
+pub fn subtree_names(name: &str) -> SimpleExpr {
+    tree_subquery(Expr::col(Column::Name).eq(name), Direction::Descendants, Column::Name)
+}
+
+fn in_tree(id: i32, direction: Direction) -> SimpleExpr {
+    Expr::col((Entity, Column::Id)).binary(
+        BinOper::In,
+        tree_subquery(Expr::col(Column::Id).eq(id), direction, Column::Id),
+    )
+}
 
 // Wrapper functions for OpenAPI documentation
 #[utoipa::path(
@@ -21,141 +115,726 @@ use utoipa::ToSchema;
         ("bearer_auth" = [])
     ),
     params(
-        ("name" = Option<String>, Query, description = "Category name to search")
+        ("name" = Option<String>, Query, description = "Category name to search"),
+        PaginationParams
     ),
     responses(
-        (status = 200, description = "List of categories", body = Vec<Model>),
+        (status = 200, description = "Page of categories (sortable by name)", body = CategoryPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching categories")
+            )
+        ),
+        (status = 400, description = "Invalid pagination or sort parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Categories"
 )]
+#[deprecated(note = "use GET /api/v1/categories")]
 pub async fn get_category_handler(
     Query(params): Query<HashMap<String, String>>,
     State(conn): State<DatabaseConnection>,
-) -> Result<Json<Vec<Model>>, AppError> {
-    get_category(Query(params), State(conn)).await
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    get_category(Query(params), State(conn), list).await
 }
 
+const LIST_SPEC: ListSpec<Column> = ListSpec {
+    sortable: &[Column::Name],
+    filterable: &[],
+    default_sort: &[],
+    cursor: Column::Name,
+};
+
 // SELECT
 pub async fn get_category(
     Query(params): Query<HashMap<String, String>>,
     State(conn): State<DatabaseConnection>,
-) -> Result<Json<Vec<Model>>, AppError> {
-    let mut condition = Condition::all();
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    let mut condition = Condition::all().add(Column::DeletedAt.is_null());
 
     if let Some(name) = params.get("name") {
         condition = condition.add(Column::Name.contains(name));
     }
 
-    match Entity::find().filter(condition).all(&conn).await {
-        Ok(categories) => Ok(Json(categories)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
-    }
+    list.fetch(&conn, Entity::find().filter(condition), &LIST_SPEC).await
 }
 
-#[derive(serde::Deserialize, ToSchema)]
+#[derive(Deserialize, ToSchema, Validate)]
 pub struct UpsertModel {
+    #[schema(example = "Laptops", min_length = 1, max_length = 100)]
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
     name: Option<String>,
+    // 상위 category 이름 (없으면 최상위 category)
+    #[schema(example = "Electronics", min_length = 1, max_length = 100)]
+    #[validate(length(min = 1, max = 100))]
+    parent: Option<String>,
+}
+
+// parent 이름으로 parent_id를 찾는다. (없는 category이면 409)
+async fn resolve_parent<C: ConnectionTrait>(conn: &C, parent: Option<&str>) -> Result<Option<i32>, AppError> {
+    let Some(parent) = parent else {
+        return Ok(None);
+    };
+    let parent = find_by_name(parent)
+        .one(conn)
+        .await?
+        .ok_or_else(|| AppError::Conflict("Parent category does not exist".into()))?;
+    Ok(Some(parent.id))
 }
 
 #[utoipa::path(
     post,
     path = "/categories",
     security(
-        ("bearer_auth" = [])
+        ("bearer_auth" = ["admin"])
     ),
     request_body = inline(UpsertModel),
     responses(
         (status = 200, description = "Category created", body = Model),
+        (status = 409, description = "Category already exists", body = ErrorResponse),
+        (status = 422, description = "Missing or invalid name", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Categories"
 )]
+#[deprecated(note = "use POST /api/v1/categories")]
 pub async fn post_category_handler(
     State(conn): State<DatabaseConnection>,
-    Json(category): Json<UpsertModel>,
+    auth_user: AuthUser,
+    ValidatedJson(category): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    post_category(State(conn), Json(category)).await
+    post_category(State(conn), auth_user, ValidatedJson(category)).await
 }
 
 // INSERT
 // #[axum::debug_handler]
 pub async fn post_category(
     State(conn): State<DatabaseConnection>,
-    Json(category): Json<UpsertModel>,
+    auth_user: AuthUser,
+    ValidatedJson(category): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
+    require_fields("Name not provided", &[("name", category.name.is_some())])?;
+
+    let txn = conn.begin().await?;
+
     let new_category = ActiveModel {
-        name: ActiveValue::Set(category.name.unwrap()),
+        name: ActiveValue::Set(category.name.unwrap_or_default()),
+        parent_id: ActiveValue::Set(resolve_parent(&txn, category.parent.as_deref()).await?),
+        ..Default::default()
     };
+    let result = new_category.insert(&txn).await?;
+    AuditEntry::created(AUDIT_ENTITY, result.id, &result)
+        .write(&txn, Some(&auth_user))
+        .await?;
+    txn.commit().await?;
 
-    match new_category.insert(&conn).await {
-        Ok(result) => Ok(Json(result)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
-    }
+    Ok(Json(result))
 }
 
 #[utoipa::path(
     delete,
     path = "/categories",
     security(
-        ("bearer_auth" = [])
+        ("bearer_auth" = ["admin"])
     ),
     params(
-        ("name" = String, Query, description = "Category name to delete")
+        ("name" = String, Query, description = "Category name to delete (exact match)"),
+        ("reassign_to" = Option<String>, Query, description = "Category to move the deleted category's products to")
     ),
     responses(
         (status = 200, description = "Category deleted", body = String),
-        (status = 400, description = "Name not provided", body = ErrorResponse),
+        (status = 400, description = "Name not provided, or reassign_to is the same category or does not exist", body = ErrorResponse),
         (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 409, description = "Category has subcategories or is used by products (code in_use, dependents lists them)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Categories"
 )]
+#[deprecated(note = "use DELETE /api/v1/categories/{name}")]
 pub async fn delete_category_handler(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<Json<&'static str>, AppError> {
-    delete_category(State(conn), Query(params)).await
+    delete_category(State(conn), auth_user, Query(params)).await
 }
 
 // DELETE
+// -- name은 정확히 일치해야 하며, 하위 category / product 확인은 v1 endpoint와 동일하다.
 pub async fn delete_category(
     State(conn): State<DatabaseConnection>,
-    Query(params): Query<HashMap<String, String>>,
+    auth_user: AuthUser,
+    Query(mut params): Query<HashMap<String, String>>,
 ) -> Result<Json<&'static str>, AppError> {
-    if params.get("name").is_none() {
-        return Err(AppError::new(
-            StatusCode::BAD_REQUEST,
-            "Name not provided"
-        ));
+    let name = params
+        .remove("name")
+        .ok_or_else(|| AppError::BadRequest("Name not provided".into()))?;
+
+    delete_by_name(&conn, &auth_user, &name, params.remove("reassign_to")).await?;
+
+    Ok(Json("Category deleted"))
+}
+
+// ---------------------------------------------------------------
+// /api/v1 RESTful endpoints
+// ---------------------------------------------------------------
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CountParams {
+    /// true이면 category별 product 수(soft delete된 product 제외)를 함께 반환
+    #[serde(default)]
+    include_counts: bool,
+}
+
+// GET /api/v1/categories 응답 항목
+#[derive(Serialize, ToSchema)]
+pub struct CategorySummary {
+    #[serde(flatten)]
+    pub category: Model,
+    // include_counts=true일 때만 포함
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 12)]
+    pub product_count: Option<u64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/categories",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("name" = Option<String>, Query, description = "Category name to search"),
+        PaginationParams,
+        CountParams
+    ),
+    responses(
+        (status = 200, description = "Page of categories (sortable by name)", body = CategorySummaryPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching categories")
+            )
+        ),
+        (status = 400, description = "Invalid pagination or sort parameters", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn list_categories_handler(
+    Query(params): Query<HashMap<String, String>>,
+    Query(counts): Query<CountParams>,
+    State(conn): State<DatabaseConnection>,
+    list: ListQuery,
+) -> Result<Page<CategorySummary>, AppError> {
+    let page = get_category(Query(params), State(conn.clone()), list).await?;
+
+    if !counts.include_counts {
+        return Ok(page.map(|category| CategorySummary { category, product_count: None }));
     }
 
-    let category = match Entity::find()
-        .filter(Condition::any().add(Column::Name.contains(params.get("name").unwrap())))
-        .one(&conn)
-        .await {
-            Ok(Some(category)) => category,
-            Ok(None) => return Err(AppError::new(
-                StatusCode::NOT_FOUND,
-                "Category not found"
-            )),
-            Err(_) => return Err(AppError::new(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Database error"
-            )),
+    // 현재 페이지의 category들에 대해서만 한 번의 GROUP BY 쿼리로 집계
+    let names: Vec<String> = page.items.iter().map(|category| category.name.clone()).collect();
+    let product_counts: HashMap<String, i64> = product::Entity::find()
+        .select_only()
+        .column(product::Column::Category)
+        .column_as(Expr::col(product::Column::Id).count(), "product_count")
+        .filter(product::Column::Category.is_in(names))
+        .filter(product::Column::DeletedAt.is_null())
+        .group_by(product::Column::Category)
+        .into_tuple::<(String, i64)>()
+        .all(&conn)
+        .await?
+        .into_iter()
+        .collect();
+
+    Ok(page.map(|category| {
+        let count = product_counts.get(&category.name).copied().unwrap_or_default();
+        CategorySummary { category, product_count: Some(count as u64) }
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/categories/{name}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("name" = String, Path, description = "Category name")
+    ),
+    responses(
+        (status = 200, description = "Category found", body = Model),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn get_category_by_name_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(name): Path<String>,
+) -> Result<Json<Model>, AppError> {
+    match find_by_name(&name).one(&conn).await {
+        Ok(Some(category)) => Ok(Json(category)),
+        Ok(None) => Err(AppError::NotFound("Category not found".into())),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/categories",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 201, description = "Category created", body = Model,
+            headers(
+                ("location" = String, description = "URL of the created category")
+            )
+        ),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 409, description = "Category already exists or parent category does not exist", body = ErrorResponse),
+        (status = 422, description = "Missing or invalid name", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn create_category_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    ValidatedJson(category): ValidatedJson<UpsertModel>,
+) -> Result<(StatusCode, [(HeaderName, String); 1], Json<Model>), AppError> {
+    let Json(created) = post_category(State(conn), auth_user, ValidatedJson(category)).await?;
+    let location = format!(
+        "/api/v1/categories/{}",
+        utf8_percent_encode(&created.name, NON_ALPHANUMERIC)
+    );
+
+    Ok((StatusCode::CREATED, [(header::LOCATION, location)], Json(created)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/categories/{name}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("name" = String, Path, description = "Category name (exact match)"),
+        DeleteParams
+    ),
+    responses(
+        (status = 204, description = "Category deleted"),
+        (status = 400, description = "reassign_to is the same category or does not exist", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 409, description = "Category has subcategories or is used by products (code in_use, dependents lists them)", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn delete_category_by_name_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(name): Path<String>,
+    Query(params): Query<DeleteParams>,
+) -> Result<StatusCode, AppError> {
+    delete_by_name(&conn, &auth_user, &name, params.reassign_to).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 하위 category가 없고 사용 중이 아닐 때만 삭제 (reassign_to가 있으면 product를 먼저 옮긴다)
+async fn delete_by_name(
+    conn: &DatabaseConnection,
+    auth_user: &AuthUser,
+    name: &str,
+    reassign_to: Option<String>,
+) -> Result<(), AppError> {
+    let txn = conn.begin().await?;
+
+    let category = find_by_name(name)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".into()))?;
+
+    ensure_no_children(&txn, &category).await?;
+    match reassign_to {
+        Some(target) => reassign_products(&txn, auth_user, &category, &target).await?,
+        None => ensure_unused(&txn, &category).await?,
+    }
+
+    category.clone().delete(&txn).await?;
+    AuditEntry::deleted(AUDIT_ENTITY, category.id, &category)
+        .write(&txn, Some(auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(())
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteParams {
+    /// 삭제할 category의 product를 옮길 category 이름 (지정하지 않으면 product가 있을 때 409)
+    reassign_to: Option<String>,
+}
+
+// 하위 category가 있으면 목록과 함께 409 (하위 category를 먼저 이동하거나 삭제해야 함)
+async fn ensure_no_children<C: ConnectionTrait>(conn: &C, category: &Model) -> Result<(), AppError> {
+    let children = Entity::find().filter(Column::ParentId.eq(category.id));
+    let total = children.clone().count(conn).await?;
+    if total == 0 {
+        return Ok(());
+    }
+
+    let categories = children
+        .order_by_asc(Column::Name)
+        .limit(MAX_DEPENDENTS)
+        .all(conn)
+        .await?;
+
+    Err(AppError::InUse(
+        format!("Category has {} subcategories; move or delete them first", total),
+        categories
+            .into_iter()
+            .map(|child| Dependent {
+                entity: AUDIT_ENTITY,
+                id: child.id.to_string(),
+                name: child.name,
+            })
+            .collect(),
+    ))
+}
+
+// category를 사용하는 product가 있으면 (soft delete된 product 포함) 목록과 함께 409
+async fn ensure_unused<C: ConnectionTrait>(conn: &C, category: &Model) -> Result<(), AppError> {
+    let dependents = category.find_related(product::Entity);
+    let total = dependents.clone().count(conn).await?;
+    if total == 0 {
+        return Ok(());
+    }
+
+    let products = dependents
+        .order_by_asc(product::Column::Id)
+        .limit(MAX_DEPENDENTS)
+        .all(conn)
+        .await?;
+
+    Err(AppError::InUse(
+        format!(
+            "Category is used by {} products; delete them or use reassign_to to move them",
+            total
+        ),
+        products
+            .into_iter()
+            .map(|product| Dependent {
+                entity: api::product::AUDIT_ENTITY,
+                id: product.id.to_string(),
+                name: product.title,
+            })
+            .collect(),
+    ))
+}
+
+// category의 모든 product를 target category로 옮긴다. (soft delete된 product 포함)
+async fn reassign_products<C: ConnectionTrait>(
+    conn: &C,
+    auth_user: &AuthUser,
+    category: &Model,
+    target: &str,
+) -> Result<(), AppError> {
+    if target == category.name {
+        return Err(AppError::BadRequest("reassign_to must be a different category".into()));
+    }
+    let target = find_by_name(target)
+        .one(conn)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("reassign_to category not found: {}", target)))?;
+
+    let products = category
+        .find_related(product::Entity)
+        .order_by_asc(product::Column::Id)
+        .lock_exclusive()
+        .all(conn)
+        .await?;
+    if products.is_empty() {
+        return Ok(());
+    }
+
+    let now = Utc::now().fixed_offset();
+    product::Entity::update_many()
+        .col_expr(product::Column::Category, Expr::value(target.name.clone()))
+        .col_expr(product::Column::UpdatedAt, Expr::value(now))
+        .filter(product::Column::Category.eq(&category.name))
+        .exec(conn)
+        .await?;
+
+    for before in &products {
+        let after = product::Model {
+            category: target.name.clone(),
+            updated_at: now,
+            ..before.clone()
         };
+        AuditEntry::updated(api::product::AUDIT_ENTITY, before.id, before, &after)
+            .write(conn, Some(auth_user))
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct RenameModel {
+    #[schema(example = "Notebooks", min_length = 1, max_length = 100)]
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    name: Option<String>,
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/categories/{name}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("name" = String, Path, description = "Current category name (exact match)")
+    ),
+    request_body = inline(RenameModel),
+    responses(
+        (status = 200, description = "Category renamed (products follow the new name)", body = Model),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 409, description = "A category with the new name already exists", body = ErrorResponse),
+        (status = 422, description = "Missing or invalid name", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn rename_category_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(name): Path<String>,
+    ValidatedJson(category): ValidatedJson<RenameModel>,
+) -> Result<Json<Model>, AppError> {
+    require_fields("Name not provided", &[("name", category.name.is_some())])?;
+    let new_name = category.name.unwrap_or_default();
+
+    let txn = conn.begin().await?;
+
+    let current = find_by_name(&name)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".into()))?;
+    if current.name == new_name {
+        return Ok(Json(current));
+    }
+
+    // product.category는 foreign key의 ON UPDATE CASCADE로 함께 변경된다.
+    let mut active: ActiveModel = current.clone().into();
+    active.name = ActiveValue::Set(new_name);
+    let renamed = active.update(&txn).await?;
+
+    AuditEntry::updated(AUDIT_ENTITY, renamed.id, &current, &renamed)
+        .write(&txn, Some(&auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(renamed))
+}
+
+// category tree 응답 (같은 parent의 children은 이름순)
+#[derive(Serialize, ToSchema)]
+pub struct CategoryNode {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = "Electronics")]
+    pub name: String,
+    pub children: Vec<CategoryNode>,
+}
+
+fn group_by_parent(categories: Vec<Model>) -> HashMap<Option<i32>, Vec<Model>> {
+    let mut groups: HashMap<Option<i32>, Vec<Model>> = HashMap::new();
+    for category in categories {
+        groups.entry(category.parent_id).or_default().push(category);
+    }
+    groups
+}
+
+// parent 아래의 node 목록을 재귀적으로 만든다.
+// -- 사용한 group은 map에서 제거하므로 같은 category가 두 번 나오지 않는다.
+fn build_tree(groups: &mut HashMap<Option<i32>, Vec<Model>>, parent: Option<i32>) -> Vec<CategoryNode> {
+    groups
+        .remove(&parent)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|category| CategoryNode {
+            id: category.id,
+            children: build_tree(groups, Some(category.id)),
+            name: category.name,
+        })
+        .collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/categories/tree",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "All top-level categories with nested subcategories", body = [CategoryNode]),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn category_tree_handler(
+    State(conn): State<DatabaseConnection>,
+) -> Result<Json<Vec<CategoryNode>>, AppError> {
+    let categories = Entity::find()
+        .filter(Column::DeletedAt.is_null())
+        .order_by_asc(Column::Name)
+        .all(&conn)
+        .await?;
+
+    Ok(Json(build_tree(&mut group_by_parent(categories), None)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/categories/{name}/tree",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("name" = String, Path, description = "Root category name (exact match)")
+    ),
+    responses(
+        (status = 200, description = "The category with nested subcategories", body = CategoryNode),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn category_subtree_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(name): Path<String>,
+) -> Result<Json<CategoryNode>, AppError> {
+    let root = find_by_name(&name)
+        .one(&conn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".into()))?;
+
+    let descendants = Entity::find()
+        .filter(in_tree(root.id, Direction::Descendants))
+        .filter(Column::DeletedAt.is_null())
+        .order_by_asc(Column::Name)
+        .all(&conn)
+        .await?;
+    let mut groups = group_by_parent(descendants);
+
+    Ok(Json(CategoryNode {
+        id: root.id,
+        children: build_tree(&mut groups, Some(root.id)),
+        name: root.name,
+    }))
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct MoveModel {
+    // 새 상위 category 이름 (null이면 최상위로 이동)
+    #[schema(example = "Electronics", min_length = 1, max_length = 100)]
+    #[validate(length(min = 1, max = 100))]
+    parent: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/categories/{name}/move",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("name" = String, Path, description = "Category name (exact match)")
+    ),
+    request_body = inline(MoveModel),
+    responses(
+        (status = 200, description = "Category moved", body = Model),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 409, description = "Parent does not exist or is the category itself or one of its descendants", body = ErrorResponse),
+        (status = 422, description = "Invalid parent", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn move_category_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(name): Path<String>,
+    ValidatedJson(body): ValidatedJson<MoveModel>,
+) -> Result<Json<Model>, AppError> {
+    let txn = conn.begin().await?;
+
+    let category = find_by_name(&name)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Category not found".into()))?;
+
+    let parent_id = resolve_parent(&txn, body.parent.as_deref()).await?;
+    if let Some(parent_id) = parent_id {
+        ensure_not_descendant(&txn, &category, parent_id).await?;
+    }
+    if category.parent_id == parent_id {
+        return Ok(Json(category));
+    }
+
+    let mut active: ActiveModel = category.clone().into();
+    active.parent_id = ActiveValue::Set(parent_id);
+    let moved = active.update(&txn).await?;
 
-    match category.delete(&conn).await {
-        Ok(_) => Ok(Json("Category deleted")),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+    AuditEntry::updated(AUDIT_ENTITY, moved.id, &category, &moved)
+        .write(&txn, Some(&auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(moved))
+}
+
+// 새 parent가 category 자신이거나 하위 category이면 순환이 생기므로 409
+// -- 새 parent의 상위 category들을 lock한 뒤 다시 조회하여, 동시에 실행된 다른 이동과
+//    합쳐져 순환이 생기는 경우도 막는다.
+async fn ensure_not_descendant<C: ConnectionTrait>(
+    conn: &C,
+    category: &Model,
+    parent_id: i32,
+) -> Result<(), AppError> {
+    Entity::find()
+        .filter(in_tree(parent_id, Direction::Ancestors))
+        .order_by_asc(Column::Id)
+        .lock_exclusive()
+        .all(conn)
+        .await?;
+
+    let cycle = Entity::find()
+        .filter(in_tree(parent_id, Direction::Ancestors))
+        .filter(Column::Id.eq(category.id))
+        .count(conn)
+        .await?
+        > 0;
+    if cycle {
+        return Err(AppError::Conflict(
+            "Cannot move a category under itself or one of its descendants".into(),
+        ));
     }
+    Ok(())
 }
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use axum::{extract::State, http::StatusCode, Json};
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+use tracing::warn;
+use utoipa::ToSchema;
+
+// readiness 확인 시 DB ping 제한 시간
+const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Serialize, ToSchema)]
+pub struct HealthResponse {
+    #[schema(example = "ok")]
+    pub status: &'static str,
+    // readyz에서만 포함: DB ping 결과
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "ok")]
+    pub database: Option<&'static str>,
+}
+
+// liveness: 프로세스가 요청을 처리할 수 있으면 항상 200 (DB 상태와 무관)
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    responses(
+        (status = 200, description = "Process is alive", body = HealthResponse)
+    ),
+    tag = "Health"
+)]
+pub async fn healthz_handler() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok",
+        database: None,
+    })
+}
+
+// readiness: DB ping에 성공해야 200, 실패하면 503
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    responses(
+        (status = 200, description = "Ready to serve traffic", body = HealthResponse),
+        (status = 503, description = "Database is unavailable", body = HealthResponse)
+    ),
+    tag = "Health"
+)]
+pub async fn readyz_handler(
+    State(conn): State<DatabaseConnection>,
+) -> (StatusCode, Json<HealthResponse>) {
+    let ready = match tokio::time::timeout(PING_TIMEOUT, conn.ping()).await {
+        Ok(Ok(())) => true,
+        Ok(Err(err)) => {
+            warn!("Readiness check failed: {}", err);
+            false
+        }
+        Err(_) => {
+            warn!("Readiness check timed out after {:?}", PING_TIMEOUT);
+            false
+        }
+    };
+
+    if ready {
+        (StatusCode::OK, Json(HealthResponse { status: "ok", database: Some("ok") }))
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(HealthResponse { status: "unavailable", database: Some("unavailable") }),
+        )
+    }
+}
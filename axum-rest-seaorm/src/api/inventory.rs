@@ -0,0 +1,345 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, sea_query::OnConflict, ActiveModelTrait, ActiveValue,
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QuerySelect,
+    TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::{
+    api::reservation,
+    entities::{inventory, inventory_adjustment, product},
+    utils::app_error::AppError,
+    utils::auth_user::AuthUser,
+    utils::pagination::{ListQuery, ListSpec, Page, PaginationParams},
+    utils::validated_json::ValidatedJson,
+};
+
+// 재고 변경 사유 (inventory_adjustment.reason)
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AdjustmentReason {
+    // 입고
+    Restock,
+    // 판매 (예약을 확정하면 자동으로 기록)
+    Sale,
+    // 반품
+    Return,
+    // 파손
+    Damaged,
+    // 분실
+    Lost,
+    // 재고 실사 등에 따른 보정
+    Correction,
+}
+
+impl AdjustmentReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdjustmentReason::Restock => "restock",
+            AdjustmentReason::Sale => "sale",
+            AdjustmentReason::Return => "return",
+            AdjustmentReason::Damaged => "damaged",
+            AdjustmentReason::Lost => "lost",
+            AdjustmentReason::Correction => "correction",
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct InventoryResponse {
+    #[schema(example = 1)]
+    pub product_id: i32,
+    // 보유 수량
+    #[schema(example = 10)]
+    pub quantity: i32,
+    // 예약된 수량 (예약이 확정, 취소 또는 만료될 때까지 판매할 수 없음)
+    #[schema(example = 2)]
+    pub reserved: i32,
+    // 판매 가능 수량 (quantity - reserved)
+    #[schema(example = 8)]
+    pub available: i32,
+    // 재고를 한 번도 변경하지 않은 product는 null
+    pub updated_at: Option<DateTimeWithTimeZone>,
+}
+
+impl InventoryResponse {
+    fn new(product_id: i32, inventory: Option<inventory::Model>) -> Self {
+        match inventory {
+            Some(inventory) => inventory.into(),
+            None => Self {
+                product_id,
+                quantity: 0,
+                reserved: 0,
+                available: 0,
+                updated_at: None,
+            },
+        }
+    }
+}
+
+impl From<inventory::Model> for InventoryResponse {
+    fn from(inventory: inventory::Model) -> Self {
+        Self {
+            product_id: inventory.product_id,
+            quantity: inventory.quantity,
+            reserved: inventory.reserved,
+            available: inventory.quantity - inventory.reserved,
+            updated_at: Some(inventory.updated_at),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct AdjustModel {
+    // 증감 수량 (입고는 양수, 출고는 음수)
+    #[schema(example = 10)]
+    #[validate(range(min = -1_000_000, max = 1_000_000), custom(function = "non_zero"))]
+    delta: i32,
+    reason: AdjustmentReason,
+    #[schema(example = "Weekly restock", max_length = 500)]
+    #[validate(length(max = 500))]
+    note: Option<String>,
+}
+
+fn non_zero(value: i32) -> Result<(), ValidationError> {
+    if value == 0 {
+        return Err(ValidationError::new("non_zero").with_message("must not be zero".into()));
+    }
+    Ok(())
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AdjustmentResponse {
+    pub adjustment: inventory_adjustment::Model,
+    pub inventory: InventoryResponse,
+}
+
+// 삭제되지 않은 product인지 확인
+pub async fn ensure_product<C: ConnectionTrait>(conn: &C, product_id: i32) -> Result<(), AppError> {
+    product::Entity::find_by_id(product_id)
+        .filter(product::Column::DeletedAt.is_null())
+        .one(conn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Product not found".into()))?;
+    Ok(())
+}
+
+// product의 inventory row를 lock하고, 만료된 예약 수량을 돌려놓은 재고와 만료 처리한 예약 수를 반환한다.
+// -- row가 없으면 재고 0으로 만든다. (동시에 만들어도 ON CONFLICT DO NOTHING)
+// -- 재고를 변경하는 곳은 모두 이 함수로 먼저 lock한다. (lock 순서: inventory -> reservation)
+//    SQLite는 FOR UPDATE가 없지만 첫 INSERT에서 write lock을 잡으므로 쓰기가 직렬화된다.
+pub async fn lock_inventory<C: ConnectionTrait>(
+    conn: &C,
+    product_id: i32,
+    now: DateTimeWithTimeZone,
+) -> Result<(inventory::Model, u64), DbErr> {
+    inventory::Entity::insert(inventory::ActiveModel {
+        product_id: ActiveValue::Set(product_id),
+        quantity: ActiveValue::Set(0),
+        reserved: ActiveValue::Set(0),
+        updated_at: ActiveValue::Set(now),
+    })
+    .on_conflict(
+        OnConflict::column(inventory::Column::ProductId)
+            .do_nothing()
+            .to_owned(),
+    )
+    .do_nothing()
+    .exec(conn)
+    .await?;
+
+    let inventory = inventory::Entity::find_by_id(product_id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound(format!("inventory for product {}", product_id)))?;
+
+    let (expired, released) = reservation::release_expired(conn, product_id, now).await?;
+    if released == 0 {
+        return Ok((inventory, expired));
+    }
+
+    let reserved = inventory.reserved - released;
+    let inventory = update_inventory(conn, inventory, None, Some(reserved), now).await?;
+    Ok((inventory, expired))
+}
+
+// lock_inventory로 가져온 row의 수량 변경
+pub async fn update_inventory<C: ConnectionTrait>(
+    conn: &C,
+    inventory: inventory::Model,
+    quantity: Option<i32>,
+    reserved: Option<i32>,
+    now: DateTimeWithTimeZone,
+) -> Result<inventory::Model, DbErr> {
+    let mut active: inventory::ActiveModel = inventory.into();
+    if let Some(quantity) = quantity {
+        active.quantity = ActiveValue::Set(quantity);
+    }
+    if let Some(reserved) = reserved {
+        active.reserved = ActiveValue::Set(reserved);
+    }
+    active.updated_at = ActiveValue::Set(now);
+    active.update(conn).await
+}
+
+// 재고 변경 이력 기록
+pub async fn record_adjustment<C: ConnectionTrait>(
+    conn: &C,
+    auth_user: Option<&AuthUser>,
+    inventory: &inventory::Model,
+    delta: i32,
+    reason: AdjustmentReason,
+    note: Option<String>,
+) -> Result<inventory_adjustment::Model, DbErr> {
+    inventory_adjustment::ActiveModel {
+        product_id: ActiveValue::Set(inventory.product_id),
+        delta: ActiveValue::Set(delta),
+        reason: ActiveValue::Set(reason.as_str().to_string()),
+        note: ActiveValue::Set(note),
+        quantity_after: ActiveValue::Set(inventory.quantity),
+        created_by: ActiveValue::Set(auth_user.map(|user| user.id)),
+        created_at: ActiveValue::Set(inventory.updated_at),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/inventory",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Current stock of the product", body = InventoryResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Inventory"
+)]
+pub async fn get_inventory_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(product_id): Path<i32>,
+) -> Result<Json<InventoryResponse>, AppError> {
+    ensure_product(&conn, product_id).await?;
+
+    // 만료 처리 전의 예약이 포함될 수 있다. (background task 또는 다음 재고 변경 시 반영)
+    let inventory = inventory::Entity::find_by_id(product_id).one(&conn).await?;
+    Ok(Json(InventoryResponse::new(product_id, inventory)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/{id}/inventory/adjustments",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    request_body = inline(AdjustModel),
+    responses(
+        (status = 201, description = "Stock adjusted", body = AdjustmentResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 409, description = "Not enough unreserved stock to remove, or stock would exceed the maximum quantity", body = ErrorResponse),
+        (status = 422, description = "Invalid delta, reason or note", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Inventory"
+)]
+pub async fn adjust_inventory_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(product_id): Path<i32>,
+    ValidatedJson(body): ValidatedJson<AdjustModel>,
+) -> Result<(StatusCode, Json<AdjustmentResponse>), AppError> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+
+    ensure_product(&txn, product_id).await?;
+    let (inventory, _) = lock_inventory(&txn, product_id, now).await?;
+
+    // 예약된 수량은 줄일 수 없다.
+    let quantity = inventory
+        .quantity
+        .checked_add(body.delta)
+        .ok_or_else(|| AppError::Conflict("Stock quantity would exceed the maximum".into()))?;
+    if quantity < inventory.reserved {
+        return Err(AppError::Conflict(format!(
+            "Insufficient stock: {} available",
+            inventory.quantity - inventory.reserved
+        )));
+    }
+
+    let inventory = update_inventory(&txn, inventory, Some(quantity), None, now).await?;
+    let adjustment =
+        record_adjustment(&txn, Some(&auth_user), &inventory, body.delta, body.reason, body.note).await?;
+    txn.commit().await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AdjustmentResponse {
+            adjustment,
+            inventory: inventory.into(),
+        }),
+    ))
+}
+
+const ADJUSTMENT_LIST_SPEC: ListSpec<inventory_adjustment::Column> = ListSpec {
+    sortable: &[inventory_adjustment::Column::Id, inventory_adjustment::Column::CreatedAt],
+    filterable: &[inventory_adjustment::Column::Id, inventory_adjustment::Column::CreatedAt],
+    default_sort: &[],
+    cursor: inventory_adjustment::Column::Id,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/inventory/adjustments",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID"),
+        PaginationParams,
+        ("created_at_gte" = Option<String>, Query, description = "Adjusted at or after (RFC 3339)"),
+        ("created_at_lte" = Option<String>, Query, description = "Adjusted at or before (RFC 3339)")
+    ),
+    responses(
+        (status = 200, description = "Page of stock adjustments (sortable by id, created_at)", body = InventoryAdjustmentPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of adjustments")
+            )
+        ),
+        (status = 400, description = "Invalid pagination, sort or filter parameters", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Inventory"
+)]
+pub async fn list_adjustments_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(product_id): Path<i32>,
+    list: ListQuery,
+) -> Result<Page<inventory_adjustment::Model>, AppError> {
+    ensure_product(&conn, product_id).await?;
+
+    let select = inventory_adjustment::Entity::find()
+        .filter(inventory_adjustment::Column::ProductId.eq(product_id));
+    list.fetch(&conn, select, &ADJUSTMENT_LIST_SPEC).await
+}
@@ -1,5 +1,13 @@
 pub mod users;
 pub mod category;
 pub mod product;
+pub mod product_io;
+pub mod product_image;
+pub mod inventory;
+pub mod reservation;
+pub mod orders;
+pub mod audit;
 pub mod auth;
-pub mod text;
\ No newline at end of file
+pub mod health;
+pub mod text;
+pub mod routes;
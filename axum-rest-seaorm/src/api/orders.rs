@@ -0,0 +1,459 @@
+use std::collections::{BTreeMap, HashSet};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderName, StatusCode},
+    Json,
+};
+use chrono::Utc;
+use sea_orm::{
+    prelude::{DateTimeWithTimeZone, Decimal},
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::{
+    api::inventory::{self, AdjustmentReason},
+    entities::{order_items, orders, product},
+    utils::app_error::{AppError, FieldError},
+    utils::auth_user::AuthUser,
+    utils::money,
+    utils::pagination::{ListQuery, ListSpec, Page, PaginationParams},
+    utils::validated_json::ValidatedJson,
+};
+
+// 주문 상태
+// -- pending -> paid -> shipped 순서로 진행하고, 배송 전(pending, paid)에만 취소할 수 있다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    Pending,
+    Paid,
+    Shipped,
+    Cancelled,
+}
+
+impl OrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Pending => "pending",
+            OrderStatus::Paid => "paid",
+            OrderStatus::Shipped => "shipped",
+            OrderStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(OrderStatus::Pending),
+            "paid" => Some(OrderStatus::Paid),
+            "shipped" => Some(OrderStatus::Shipped),
+            "cancelled" => Some(OrderStatus::Cancelled),
+            _ => None,
+        }
+    }
+
+    // 허용되는 상태 전이
+    pub fn can_transition_to(&self, next: OrderStatus) -> bool {
+        matches!(
+            (self, next),
+            (OrderStatus::Pending, OrderStatus::Paid)
+                | (OrderStatus::Paid, OrderStatus::Shipped)
+                | (OrderStatus::Pending, OrderStatus::Cancelled)
+                | (OrderStatus::Paid, OrderStatus::Cancelled)
+        )
+    }
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct CheckoutModel {
+    #[validate(length(min = 1, max = 50), nested)]
+    items: Vec<CheckoutItem>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct CheckoutItem {
+    #[schema(example = 1)]
+    product_id: i32,
+    #[schema(example = 2, minimum = 1, maximum = 10000)]
+    #[validate(range(min = 1, max = 10000))]
+    quantity: i32,
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct StatusModel {
+    status: OrderStatus,
+}
+
+// 주문 상세 (주문 + 주문 항목)
+#[derive(Serialize, ToSchema)]
+pub struct OrderResponse {
+    #[serde(flatten)]
+    pub order: orders::Model,
+    pub items: Vec<order_items::Model>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrderQueryParams {
+    /// 주문 상태 (pending, paid, shipped, cancelled)
+    status: Option<String>,
+    /// 주문한 사용자 ID (관리자만 사용 가능, 생략하면 모든 사용자의 주문)
+    user_id: Option<i32>,
+}
+
+const LIST_SPEC: ListSpec<orders::Column> = ListSpec {
+    sortable: &[orders::Column::Id, orders::Column::CreatedAt, orders::Column::Total],
+    filterable: &[orders::Column::Id, orders::Column::CreatedAt, orders::Column::Total],
+    default_sort: &[],
+    cursor: orders::Column::Id,
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/orders",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = inline(CheckoutModel),
+    responses(
+        (status = 201, description = "Order placed with product titles and prices as of checkout", body = OrderResponse,
+            headers(
+                ("location" = String, description = "URL of the created order")
+            )
+        ),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 409, description = "Not enough available stock", body = ErrorResponse),
+        (status = 422, description = "Invalid items (empty, duplicated products, mixed currencies or total too large)", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn checkout_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    ValidatedJson(body): ValidatedJson<CheckoutModel>,
+) -> Result<(StatusCode, [(HeaderName, String); 1], Json<OrderResponse>), AppError> {
+    let mut seen = HashSet::new();
+    if !body.items.iter().all(|item| seen.insert(item.product_id)) {
+        return Err(items_error("must not contain the same product more than once"));
+    }
+
+    // 재고 lock 순서를 고정하기 위해 product_id 순으로 처리 (deadlock 방지)
+    let quantities: BTreeMap<i32, i32> = body
+        .items
+        .iter()
+        .map(|item| (item.product_id, item.quantity))
+        .collect();
+
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+
+    let products: BTreeMap<i32, product::Model> = product::Entity::find()
+        .filter(product::Column::Id.is_in(quantities.keys().copied()))
+        .filter(product::Column::DeletedAt.is_null())
+        .all(&txn)
+        .await?
+        .into_iter()
+        .map(|product| (product.id, product))
+        .collect();
+    if let Some(missing) = quantities.keys().find(|id| !products.contains_key(id)) {
+        return Err(AppError::NotFound(format!("Product {} not found", missing)));
+    }
+
+    let currency = products.values().next().map(|product| product.currency.clone()).unwrap_or_default();
+    if products.values().any(|product| product.currency != currency) {
+        return Err(items_error("all products must have the same currency"));
+    }
+
+    // 주문 시점의 가격으로 합계를 계산
+    // -- Decimal overflow나 DB column 범위를 넘는 합계는 DB error(500) 대신 422로 거부
+    let mut line_totals = BTreeMap::new();
+    let mut total = Decimal::ZERO;
+    for product in products.values() {
+        let line_total = product
+            .price
+            .checked_mul(Decimal::from(quantities[&product.id]))
+            .ok_or_else(|| items_error("order total is too large"))?;
+        total = total
+            .checked_add(line_total)
+            .ok_or_else(|| items_error("order total is too large"))?;
+        line_totals.insert(product.id, line_total);
+    }
+    if !money::fits_column(&total) {
+        return Err(items_error("order total is too large"));
+    }
+
+    let order = orders::ActiveModel {
+        user_id: ActiveValue::Set(auth_user.id),
+        status: ActiveValue::Set(OrderStatus::Pending.as_str().to_string()),
+        currency: ActiveValue::Set(currency),
+        total: ActiveValue::Set(total),
+        created_at: ActiveValue::Set(now),
+        updated_at: ActiveValue::Set(now),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    let mut items = Vec::with_capacity(products.len());
+    for (product_id, product) in products {
+        let quantity = quantities[&product_id];
+
+        let (stock, _) = inventory::lock_inventory(&txn, product_id, now).await?;
+        let available = stock.quantity - stock.reserved;
+        if quantity > available {
+            return Err(AppError::Conflict(format!(
+                "Insufficient stock for product {}: {} available",
+                product_id, available
+            )));
+        }
+        let stock_quantity = stock.quantity - quantity;
+        let stock = inventory::update_inventory(&txn, stock, Some(stock_quantity), None, now).await?;
+        inventory::record_adjustment(
+            &txn,
+            Some(&auth_user),
+            &stock,
+            -quantity,
+            AdjustmentReason::Sale,
+            Some(format!("order #{}", order.id)),
+        )
+        .await?;
+
+        let item = order_items::ActiveModel {
+            order_id: ActiveValue::Set(order.id),
+            product_id: ActiveValue::Set(product_id),
+            title: ActiveValue::Set(product.title),
+            unit_price: ActiveValue::Set(product.price),
+            quantity: ActiveValue::Set(quantity),
+            line_total: ActiveValue::Set(line_totals[&product_id]),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        items.push(item);
+    }
+    txn.commit().await?;
+
+    let location = format!("/api/v1/orders/{}", order.id);
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, location)],
+        Json(OrderResponse { order, items }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/orders",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        OrderQueryParams,
+        PaginationParams,
+        ("created_at_gte" = Option<String>, Query, description = "Ordered at or after (RFC 3339)"),
+        ("created_at_lte" = Option<String>, Query, description = "Ordered at or before (RFC 3339)"),
+        ("total_gte" = Option<String>, Query, description = "Minimum total (inclusive)"),
+        ("total_lte" = Option<String>, Query, description = "Maximum total (inclusive)")
+    ),
+    responses(
+        (status = 200, description = "Page of the caller's orders, or any user's orders for admins (sortable by id, created_at, total)", body = OrderPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching orders")
+            )
+        ),
+        (status = 400, description = "Invalid status, pagination, sort or filter parameters", body = ErrorResponse),
+        (status = 403, description = "user_id of another user requires admin role", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn list_orders_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Query(params): Query<OrderQueryParams>,
+    list: ListQuery,
+) -> Result<Page<orders::Model>, AppError> {
+    let mut condition = Condition::all();
+
+    // 일반 사용자는 본인의 주문만 조회할 수 있다.
+    if auth_user.is_admin() {
+        if let Some(user_id) = params.user_id {
+            condition = condition.add(orders::Column::UserId.eq(user_id));
+        }
+    } else {
+        if let Some(user_id) = params.user_id {
+            auth_user.ensure_owner_or_admin(user_id)?;
+        }
+        condition = condition.add(orders::Column::UserId.eq(auth_user.id));
+    }
+    if let Some(status) = params.status {
+        let status = OrderStatus::parse(&status)
+            .ok_or_else(|| AppError::BadRequest(format!("Invalid value for status: {}", status)))?;
+        condition = condition.add(orders::Column::Status.eq(status.as_str()));
+    }
+
+    list.fetch(&conn, orders::Entity::find().filter(condition), &LIST_SPEC).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/orders/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i64, Path, description = "Order ID")
+    ),
+    responses(
+        (status = 200, description = "Order with its items", body = OrderResponse),
+        (status = 403, description = "Order belongs to another user", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn get_order_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<OrderResponse>, AppError> {
+    let order = orders::Entity::find_by_id(id)
+        .one(&conn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Order not found".into()))?;
+    auth_user.ensure_owner_or_admin(order.user_id)?;
+
+    let items = find_items(&conn, id).await?;
+    Ok(Json(OrderResponse { order, items }))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/orders/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i64, Path, description = "Order ID")
+    ),
+    request_body = inline(StatusModel),
+    responses(
+        (status = 200, description = "Order status changed (paid and shipped require admin role; owners may cancel pending orders)", body = OrderResponse),
+        (status = 403, description = "Not allowed to make this transition", body = ErrorResponse),
+        (status = 404, description = "Order not found", body = ErrorResponse),
+        (status = 409, description = "Transition not allowed from the current status, or restocking would exceed the maximum quantity", body = ErrorResponse),
+        (status = 422, description = "Unknown status", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Orders"
+)]
+pub async fn update_order_status_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i64>,
+    ValidatedJson(body): ValidatedJson<StatusModel>,
+) -> Result<Json<OrderResponse>, AppError> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+
+    let order = orders::Entity::find_by_id(id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Order not found".into()))?;
+    auth_user.ensure_owner_or_admin(order.user_id)?;
+
+    let current = OrderStatus::parse(&order.status)
+        .ok_or_else(|| AppError::Internal(format!("Unknown order status: {}", order.status)))?;
+    let next = body.status;
+    if !current.can_transition_to(next) {
+        return Err(AppError::Conflict(format!(
+            "Cannot change order status from {} to {}",
+            current.as_str(),
+            next.as_str()
+        )));
+    }
+
+    // 결제/배송 처리는 관리자만, 주문자는 결제 전인 주문만 취소할 수 있다.
+    let allowed = auth_user.is_admin() || (current == OrderStatus::Pending && next == OrderStatus::Cancelled);
+    if !allowed {
+        return Err(AppError::Forbidden(format!(
+            "Not allowed to change order status to {}",
+            next.as_str()
+        )));
+    }
+
+    let items = find_items(&txn, id).await?;
+    if next == OrderStatus::Cancelled {
+        restock(&txn, &auth_user, &order, &items, now).await?;
+    }
+
+    let mut active: orders::ActiveModel = order.into();
+    active.status = ActiveValue::Set(next.as_str().to_string());
+    active.updated_at = ActiveValue::Set(now);
+    match next {
+        OrderStatus::Paid => active.paid_at = ActiveValue::Set(Some(now)),
+        OrderStatus::Shipped => active.shipped_at = ActiveValue::Set(Some(now)),
+        OrderStatus::Cancelled => active.cancelled_at = ActiveValue::Set(Some(now)),
+        OrderStatus::Pending => {}
+    }
+    let order = active.update(&txn).await?;
+    txn.commit().await?;
+
+    Ok(Json(OrderResponse { order, items }))
+}
+
+async fn find_items<C: ConnectionTrait>(
+    conn: &C,
+    order_id: i64,
+) -> Result<Vec<order_items::Model>, AppError> {
+    let items = order_items::Entity::find()
+        .filter(order_items::Column::OrderId.eq(order_id))
+        .order_by_asc(order_items::Column::Id)
+        .all(conn)
+        .await?;
+    Ok(items)
+}
+
+// 취소된 주문의 수량을 재고로 돌려놓는다. (checkout과 같은 product_id 순서로 lock)
+async fn restock(
+    txn: &DatabaseTransaction,
+    auth_user: &AuthUser,
+    order: &orders::Model,
+    items: &[order_items::Model],
+    now: DateTimeWithTimeZone,
+) -> Result<(), AppError> {
+    let mut items: Vec<&order_items::Model> = items.iter().collect();
+    items.sort_by_key(|item| item.product_id);
+
+    for item in items {
+        let (stock, _) = inventory::lock_inventory(txn, item.product_id, now).await?;
+        let quantity = stock.quantity.checked_add(item.quantity).ok_or_else(|| {
+            AppError::Conflict(format!("Stock quantity of product {} would exceed the maximum", item.product_id))
+        })?;
+        let stock = inventory::update_inventory(txn, stock, Some(quantity), None, now).await?;
+        inventory::record_adjustment(
+            txn,
+            Some(auth_user),
+            &stock,
+            item.quantity,
+            AdjustmentReason::Return,
+            Some(format!("order #{} cancelled", order.id)),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+fn items_error(message: &str) -> AppError {
+    AppError::Validation(
+        "Invalid request body".into(),
+        vec![FieldError::new("items", message)],
+    )
+}
@@ -1,32 +1,96 @@
 use axum::{
-    extract::{Query, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, HeaderName, StatusCode},
     Json,
 };
+use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
-    ModelTrait, QueryFilter,
+    prelude::Decimal, sea_query::{Alias, BinOper, Expr, SimpleExpr}, ActiveModelTrait, ActiveValue, ColumnTrait,
+    Condition, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, EntityTrait,
+    FromQueryResult, Order, QueryFilter, QueryOrder, QueryResult, QuerySelect, Select,
+    TransactionTrait,
 };
-use serde::Deserialize;
-use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
 
 use crate::{
+    api,
+    entities::price_history,
     entities::product::{ActiveModel, Column, Entity, Model},
-    utils::app_error::AppError,
+    utils::app_error::{AppError, FieldError},
+    utils::audit::AuditEntry,
+    utils::auth_user::AuthUser,
+    utils::money::{self, check_scale, currency_code, non_negative_amount, DEFAULT_CURRENCY},
+    utils::pagination::{ListQuery, ListSpec, Page, PaginationParams},
+    utils::validated_json::{not_blank, require_fields, ValidatedJson},
 };
 
-#[derive(Deserialize, ToSchema)]
+#[derive(Deserialize, ToSchema, Validate)]
 pub struct UpsertModel {
-    #[schema(example = 1)]
+    #[schema(example = 1, minimum = 1)]
+    #[validate(range(min = 1))]
     id: Option<i32>,
-    #[schema(example = "Laptop")]
+    #[schema(example = "Laptop", min_length = 1, max_length = 200)]
+    #[validate(length(min = 1, max = 200), custom(function = "not_blank"))]
     title: Option<String>,
-    #[schema(example = 1200)]
-    price: Option<i32>,
-    #[schema(example = "Electronics")]
+    #[schema(value_type = Option<String>, example = "1200")]
+    #[serde(default, deserialize_with = "money::optional_amount::deserialize")]
+    #[validate(custom(function = "non_negative_amount"))]
+    price: Option<Decimal>,
+    // 생략하면 KRW (PATCH에서는 기존 값 유지)
+    #[schema(example = "KRW", min_length = 3, max_length = 3)]
+    #[validate(custom(function = "currency_code"))]
+    currency: Option<String>,
+    #[schema(example = "Electronics", min_length = 1, max_length = 100)]
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
     category: Option<String>,
 }
 
+// audit_log.entity 값
+pub const AUDIT_ENTITY: &str = "product";
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SoftDeleteParams {
+    /// true이면 soft delete된 product도 포함 (admin 전용)
+    #[serde(default)]
+    include_deleted: bool,
+}
+
+#[derive(Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryFilterParams {
+    /// true이면 category와 이름이 정확히 일치하는 category 및 모든 하위 category의 product를 조회
+    #[serde(default)]
+    include_descendants: bool,
+}
+
+// soft delete된 product를 제외하는 조건 (include_deleted=true는 admin만 사용 가능)
+fn visible(auth_user: &AuthUser, params: &SoftDeleteParams) -> Result<Condition, AppError> {
+    if !params.include_deleted {
+        return Ok(Condition::all().add(Column::DeletedAt.is_null()));
+    }
+    if !auth_user.is_admin() {
+        return Err(AppError::Forbidden("include_deleted requires admin role".into()));
+    }
+    Ok(Condition::all())
+}
+
+// soft delete: row는 남겨두고 deleted_at만 설정한다. (POST /api/v1/products/{id}/restore로 복구)
+async fn soft_delete<C: ConnectionTrait>(conn: &C, condition: Condition) -> Result<u64, DbErr> {
+    let now = Utc::now().fixed_offset();
+
+    let result = Entity::update_many()
+        .col_expr(Column::DeletedAt, Expr::value(now))
+        .col_expr(Column::UpdatedAt, Expr::value(now))
+        .filter(condition)
+        .filter(Column::DeletedAt.is_null())
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected)
+}
+
 // Wrapper functions for OpenAPI documentation
 #[utoipa::path(
     get,
@@ -37,27 +101,64 @@ pub struct UpsertModel {
     params(
         ("id" = Option<i32>, Query, description = "Product ID"),
         ("title" = Option<String>, Query, description = "Product title to search"),
-        ("price" = Option<i32>, Query, description = "Product price"),
-        ("category" = Option<String>, Query, description = "Product category")
+        ("price" = Option<String>, Query, description = "Product price (decimal, e.g. 19.99)"),
+        ("currency" = Option<String>, Query, description = "ISO 4217 currency code"),
+        ("category" = Option<String>, Query, description = "Product category"),
+        PaginationParams,
+        SoftDeleteParams,
+        ("price_gte" = Option<String>, Query, description = "Minimum price (inclusive)"),
+        ("price_lte" = Option<String>, Query, description = "Maximum price (inclusive)"),
+        ("id_gte" = Option<i32>, Query, description = "Minimum product ID"),
+        ("id_lte" = Option<i32>, Query, description = "Maximum product ID")
     ),
     responses(
-        (status = 200, description = "List of products", body = Vec<Model>),
+        (status = 200, description = "Page of products (sortable by id, title, price, category)", body = ProductPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching products")
+            )
+        ),
+        (status = 400, description = "Invalid pagination, sort or filter parameters", body = ErrorResponse),
+        (status = 403, description = "include_deleted requires admin role", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Products"
 )]
+#[deprecated(note = "use GET /api/v1/products")]
 pub async fn get_product_handler(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<UpsertModel>,
-) -> Result<Json<Vec<Model>>, AppError> {
-    get_product(State(conn), Query(params)).await
+    Query(deleted): Query<SoftDeleteParams>,
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    get_product(
+        State(conn),
+        auth_user,
+        Query(params),
+        Query(deleted),
+        Query(CategoryFilterParams::default()),
+        list,
+    )
+    .await
 }
 
+const LIST_SPEC: ListSpec<Column> = ListSpec {
+    sortable: &[Column::Id, Column::Title, Column::Price, Column::Category],
+    filterable: &[Column::Id, Column::Price],
+    default_sort: &[],
+    cursor: Column::Id,
+};
+
 pub async fn get_product(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<UpsertModel>,
-) -> Result<Json<Vec<Model>>, AppError> {
-    let mut condition = Condition::all();
+    Query(deleted): Query<SoftDeleteParams>,
+    Query(tree): Query<CategoryFilterParams>,
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    let mut condition = visible(&auth_user, &deleted)?;
 
     if let Some(id) = params.id {
         condition = condition.add(Column::Id.eq(id));
@@ -71,79 +172,103 @@ pub async fn get_product(
         condition = condition.add(Column::Price.eq(price));
     }
 
-    if let Some(category) = params.category {
-        condition = condition.add(Column::Category.contains(category));
+    if let Some(currency) = params.currency {
+        condition = condition.add(Column::Currency.eq(currency));
     }
-    
-    match Entity::find().filter(condition).all(&conn).await {
-        Ok(products) => Ok(Json(products)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+
+    if let Some(category) = params.category {
+        if tree.include_descendants {
+            // 재귀 CTE로 하위 category 이름을 모두 구해서 IN 조건으로 사용
+            condition = condition.add(
+                Expr::col((Entity, Column::Category))
+                    .binary(BinOper::In, api::category::subtree_names(&category)),
+            );
+        } else {
+            condition = condition.add(Column::Category.contains(category));
+        }
     }
+
+    list.fetch(&conn, Entity::find().filter(condition), &LIST_SPEC).await
 }
 
 #[utoipa::path(
     post,
     path = "/product",
     security(
-        ("bearer_auth" = [])
+        ("bearer_auth" = ["admin"])
     ),
     request_body = inline(UpsertModel),
     responses(
         (status = 200, description = "Product created", body = Model),
+        (status = 422, description = "Missing or invalid product fields", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Products"
 )]
+#[deprecated(note = "use POST /api/v1/products")]
 pub async fn post_product_handler(
     State(conn): State<DatabaseConnection>,
-    Json(product): Json<UpsertModel>,
+    auth_user: AuthUser,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    post_product(State(conn), Json(product)).await
+    post_product(State(conn), auth_user, ValidatedJson(product)).await
 }
 
 // INSERT
 pub async fn post_product(
     State(conn): State<DatabaseConnection>,
-    Json(product): Json<UpsertModel>,
+    auth_user: AuthUser,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
+    require_all_fields(&product)?;
+
+    let price = product.price.unwrap_or_default();
+    let currency = product.currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string());
+    check_scale("price", price, &currency)?;
+
     let new_product = ActiveModel {
         id: ActiveValue::NotSet,
-        title: ActiveValue::Set(product.title.unwrap()),
-        price: ActiveValue::Set(product.price.unwrap()),
-        category: ActiveValue::Set(product.category.unwrap()),
+        title: ActiveValue::Set(product.title.unwrap_or_default()),
+        price: ActiveValue::Set(price),
+        currency: ActiveValue::Set(currency),
+        category: ActiveValue::Set(product.category.unwrap_or_default()),
+        ..Default::default()
     };
 
-    match new_product.insert(&conn).await {
-        Ok(inserted_product) => Ok(Json(inserted_product)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
-    }
+    let txn = conn.begin().await?;
+    let inserted_product = new_product.insert(&txn).await?;
+    AuditEntry::created(AUDIT_ENTITY, inserted_product.id, &inserted_product)
+        .write(&txn, Some(&auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(inserted_product))
 }
 
 #[utoipa::path(
     put,
     path = "/product",
     security(
-        ("bearer_auth" = [])
+        ("bearer_auth" = ["admin"])
     ),
     request_body = inline(UpsertModel),
     responses(
         (status = 200, description = "Product updated", body = Model),
         (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 422, description = "Invalid product fields", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Products"
 )]
+#[deprecated(note = "use PUT/PATCH /api/v1/products/{id}")]
 pub async fn put_product_handler(
     State(conn): State<DatabaseConnection>,
-    Json(product): Json<UpsertModel>,
+    auth_user: AuthUser,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    put_product(State(conn), Json(product)).await
+    put_product(State(conn), auth_user, ValidatedJson(product)).await
 }
 
 // UPDATE
@@ -152,106 +277,794 @@ pub async fn put_product_handler(
 //{
 //     "id": 1,
 //     "title": "test",
-//     "price": 100,
+//     "price": "100",
 //     "category": "test"
 // }
 pub async fn put_product(
     State(conn): State<DatabaseConnection>,
-    Json(product): Json<UpsertModel>,
+    auth_user: AuthUser,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    let result = match Entity::find_by_id(product.id.unwrap())
-        .one(&conn).await {
-            Ok(result) => result.ok_or(AppError::new(
-                StatusCode::NOT_FOUND,
-                "Product not found"
-            ))?,
-            Err(_) => return Err(AppError::new(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Database error"
-            )),
+    let id = product.id.ok_or_else(|| AppError::Validation(
+        "ID not provided".into(),
+        vec![FieldError::new("id", "is required")],
+    ))?;
+
+    update_product(&conn, &auth_user, id, product).await.map(Json)
+}
+
+// 전달된 필드만 변경
+async fn update_product(
+    conn: &DatabaseConnection,
+    auth_user: &AuthUser,
+    id: i32,
+    product: UpsertModel,
+) -> Result<Model, AppError> {
+    let txn = conn.begin().await?;
+
+    let result = match Entity::find_by_id(id)
+        .filter(Column::DeletedAt.is_null())
+        .lock_exclusive()
+        .one(&txn).await {
+            Ok(result) => result.ok_or(AppError::NotFound("Product not found".into()))?,
+            Err(err) => return Err(err.into()),
         };
 
+    let price = product.price.unwrap_or(result.price);
+    let currency = product.currency.unwrap_or(result.currency.clone());
+    check_scale("price", price, &currency)?;
+
     let new_product = ActiveModel {
         id: ActiveValue::Set(result.id),
-        title: ActiveValue::Set(product.title.unwrap_or(result.title)),
-        price: ActiveValue::Set(product.price.unwrap_or(result.price)),
-        category: ActiveValue::Set(product.category.unwrap_or(result.category)),
+        title: ActiveValue::Set(product.title.unwrap_or(result.title.clone())),
+        price: ActiveValue::Set(price),
+        currency: ActiveValue::Set(currency),
+        category: ActiveValue::Set(product.category.unwrap_or(result.category.clone())),
+        ..Default::default()
     };
 
-    match new_product.update(&conn).await {
-        Ok(updated_product) => Ok(Json(updated_product)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+    let updated_product = new_product.update(&txn).await?;
+    record_price_change(&txn, auth_user, &result, &updated_product).await?;
+    AuditEntry::updated(AUDIT_ENTITY, id, &result, &updated_product)
+        .write(&txn, Some(auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(updated_product)
+}
+
+// price 또는 currency가 바뀌었으면 price_history에 기록
+// -- 금액 비교는 scale과 관계없이 값으로 한다. (1200 == 1200.0000)
+async fn record_price_change<C: ConnectionTrait>(
+    conn: &C,
+    auth_user: &AuthUser,
+    before: &Model,
+    after: &Model,
+) -> Result<(), DbErr> {
+    if before.price == after.price && before.currency == after.currency {
+        return Ok(());
+    }
+
+    price_history::ActiveModel {
+        product_id: ActiveValue::Set(after.id),
+        old_price: ActiveValue::Set(before.price),
+        old_currency: ActiveValue::Set(before.currency.clone()),
+        new_price: ActiveValue::Set(after.price),
+        new_currency: ActiveValue::Set(after.currency.clone()),
+        changed_by: ActiveValue::Set(Some(auth_user.id)),
+        changed_at: ActiveValue::Set(Utc::now().fixed_offset()),
+        ..Default::default()
     }
+    .insert(conn)
+    .await?;
+    Ok(())
+}
+
+// title, price, category가 모두 전달되었는지 확인 (POST, PUT)
+fn require_all_fields(product: &UpsertModel) -> Result<(), AppError> {
+    require_fields(
+        "Missing product fields",
+        &[
+            ("title", product.title.is_some()),
+            ("price", product.price.is_some()),
+            ("category", product.category.is_some()),
+        ],
+    )
 }
 
 #[utoipa::path(
     delete,
     path = "/product",
     security(
-        ("bearer_auth" = [])
+        ("bearer_auth" = ["admin"])
     ),
     params(
-        ("id" = Option<i32>, Query, description = "Product ID"),
-        ("title" = Option<String>, Query, description = "Product title"),
-        ("price" = Option<i32>, Query, description = "Product price"),
-        ("category" = Option<String>, Query, description = "Product category")
+        ("id" = i32, Query, description = "Product ID (exact match)")
     ),
     responses(
         (status = 200, description = "Product deleted", body = String),
+        (status = 400, description = "id not provided or filter parameters used (use DELETE /api/v1/products)", body = ErrorResponse),
         (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Products"
 )]
+#[deprecated(note = "use DELETE /api/v1/products/{id}")]
 pub async fn delete_product_handler(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<UpsertModel>,
 ) -> Result<Json<&'static str>, AppError> {
-    delete_product(State(conn), Query(params)).await
+    delete_product(State(conn), auth_user, Query(params)).await
 }
 
-// DELETE /product?id=1&title=test&price=100&category=test
+// DELETE /product?id=1
+// -- 단건 삭제는 정확한 id로만 가능하다. 조건에 맞는 여러 건을 삭제하려면 bulk delete를 사용한다.
 pub async fn delete_product(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<UpsertModel>,
 ) -> Result<Json<&'static str>, AppError> {
-    let mut condition = Condition::any();
+    if params.title.is_some()
+        || params.price.is_some()
+        || params.currency.is_some()
+        || params.category.is_some()
+    {
+        return Err(AppError::BadRequest(
+            "Only id is accepted; use DELETE /api/v1/products for filtered bulk deletes".into(),
+        ));
+    }
 
-    if let Some(id) = params.id {
-        condition = condition.add(Column::Id.eq(id));
+    let id = params
+        .id
+        .ok_or_else(|| AppError::BadRequest("Product id not provided".into()))?;
+
+    delete_product_by_id(&conn, &auth_user, id).await?;
+    Ok(Json("Product deleted"))
+}
+
+// 단건 soft delete (삭제 전 상태를 audit log에 기록)
+async fn delete_product_by_id(
+    conn: &DatabaseConnection,
+    auth_user: &AuthUser,
+    id: i32,
+) -> Result<(), AppError> {
+    let txn = conn.begin().await?;
+
+    let product = Entity::find_by_id(id)
+        .filter(Column::DeletedAt.is_null())
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Product not found".into()))?;
+
+    soft_delete(&txn, Condition::all().add(Column::Id.eq(id))).await?;
+    AuditEntry::deleted(AUDIT_ENTITY, id, &product)
+        .write(&txn, Some(auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------
+// /api/v1 RESTful endpoints
+// ---------------------------------------------------------------
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("title" = Option<String>, Query, description = "Product title to search"),
+        ("price" = Option<String>, Query, description = "Product price (decimal, e.g. 19.99)"),
+        ("currency" = Option<String>, Query, description = "ISO 4217 currency code"),
+        ("category" = Option<String>, Query, description = "Product category"),
+        PaginationParams,
+        SoftDeleteParams,
+        CategoryFilterParams,
+        ("price_gte" = Option<String>, Query, description = "Minimum price (inclusive)"),
+        ("price_lte" = Option<String>, Query, description = "Maximum price (inclusive)"),
+        ("id_gte" = Option<i32>, Query, description = "Minimum product ID"),
+        ("id_lte" = Option<i32>, Query, description = "Maximum product ID")
+    ),
+    responses(
+        (status = 200, description = "Page of products (sortable by id, title, price, category)", body = ProductPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching products")
+            )
+        ),
+        (status = 400, description = "Invalid pagination, sort or filter parameters", body = ErrorResponse),
+        (status = 403, description = "include_deleted requires admin role", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn list_products_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Query(params): Query<UpsertModel>,
+    Query(deleted): Query<SoftDeleteParams>,
+    Query(tree): Query<CategoryFilterParams>,
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    get_product(State(conn), auth_user, Query(params), Query(deleted), Query(tree), list).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID"),
+        SoftDeleteParams
+    ),
+    responses(
+        (status = 200, description = "Product found", body = Model),
+        (status = 403, description = "include_deleted requires admin role", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn get_product_by_id_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    Query(deleted): Query<SoftDeleteParams>,
+) -> Result<Json<Model>, AppError> {
+    let condition = visible(&auth_user, &deleted)?;
+
+    match Entity::find_by_id(id).filter(condition).one(&conn).await {
+        Ok(Some(product)) => Ok(Json(product)),
+        Ok(None) => Err(AppError::NotFound("Product not found".into())),
+        Err(err) => Err(err.into()),
+    }
+}
+
+// 검색 결과 수 (limit)
+const SEARCH_DEFAULT_LIMIT: u64 = 20;
+const SEARCH_MAX_LIMIT: u64 = 100;
+// 검색어 단어 수 제한 (tsquery가 지나치게 커지는 것을 방지)
+const SEARCH_MAX_TERMS: usize = 10;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchParams {
+    /// 검색어: 모든 단어를 포함하는 product를 찾으며 각 단어는 접두어로 일치 (자동완성에 사용)
+    q: Option<String>,
+    /// 최대 결과 수 (기본 20, 최대 100)
+    limit: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchHit {
+    #[serde(flatten)]
+    pub product: Model,
+    // 검색어와의 관련도 (클수록 관련도가 높음, 같은 요청의 결과끼리만 비교 가능)
+    #[schema(example = 0.0607927)]
+    pub rank: f32,
+    // 검색어와 일치하는 단어를 <mark></mark>로 감싼 title (HTML escape되지 않음)
+    #[schema(example = "<mark>Mechanical</mark> Keyboard")]
+    pub highlight: String,
+}
+
+impl FromQueryResult for SearchHit {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        Ok(Self {
+            product: Model::from_query_result(res, pre)?,
+            rank: res.try_get(pre, "rank")?,
+            highlight: res.try_get(pre, "highlight")?,
+        })
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SearchResponse {
+    #[schema(example = "mech key")]
+    pub query: String,
+    pub items: Vec<SearchHit>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/search",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(SearchParams),
+    responses(
+        (status = 200, description = "Matching products ordered by relevance", body = SearchResponse),
+        (status = 400, description = "q has no searchable words or limit is out of range", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn search_products_handler(
+    State(conn): State<DatabaseConnection>,
+    Query(params): Query<SearchParams>,
+) -> Result<Json<SearchResponse>, AppError> {
+    search_products(&conn, params).await.map(Json)
+}
+
+// GET /api/v1/products/search?q=mech+key&limit=5
+// -- Postgres: search_vector(tsvector)를 GIN index로 검색하고 ts_rank 순으로 정렬
+// -- SQLite: 단어별 LIKE 검색 (단어 시작 부분에서 일치할수록 높은 순위)
+// -- soft delete된 product는 제외한다.
+pub async fn search_products(
+    conn: &DatabaseConnection,
+    params: SearchParams,
+) -> Result<SearchResponse, AppError> {
+    let query = params.q.unwrap_or_default();
+    let terms = search_terms(&query);
+    if terms.is_empty() {
+        return Err(AppError::BadRequest("q must contain at least one letter or digit".into()));
+    }
+
+    let limit = params.limit.unwrap_or(SEARCH_DEFAULT_LIMIT);
+    if !(1..=SEARCH_MAX_LIMIT).contains(&limit) {
+        return Err(AppError::BadRequest(format!(
+            "limit must be between 1 and {}",
+            SEARCH_MAX_LIMIT
+        )));
+    }
+
+    let select = Entity::find().filter(Column::DeletedAt.is_null());
+    let select = if conn.get_database_backend() == DatabaseBackend::Sqlite {
+        like_search(select, &terms)
+    } else {
+        full_text_search(select, &terms)
+    };
+
+    let mut items = select
+        .order_by(Expr::col(Alias::new("rank")), Order::Desc)
+        .order_by(Column::Id, Order::Asc)
+        .limit(limit)
+        .into_model::<SearchHit>()
+        .all(conn)
+        .await?;
+
+    if conn.get_database_backend() == DatabaseBackend::Sqlite {
+        for item in &mut items {
+            item.highlight = highlight(&item.product.title, &terms);
+        }
     }
 
+    Ok(SearchResponse { query, items })
+}
+
+// 검색어를 소문자 단어로 나눈다.
+// -- 문자/숫자 이외의 문자는 모두 구분자로 취급하므로 tsquery 연산자나 LIKE wildcard가 섞이지 않는다.
+fn search_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .take(SEARCH_MAX_TERMS)
+        .collect()
+}
+
+// 'mech':* && 'key':*
+// -- 사용자 입력을 tsquery 문법으로 직접 조합하지 않고 plainto_tsquery로 lexeme을 만든 뒤
+//    출력 형식('lexeme')에 접두어 표시(:*)만 붙이므로 따옴표나 연산자가 있어도 syntax error가 나지 않는다.
+// -- lexeme이 없는 단어(parser가 무시하는 문자)는 빈 tsquery가 되어 조건에서 빠진다.
+fn ts_query(terms: &[String]) -> SimpleExpr {
+    terms
+        .iter()
+        .map(|term| {
+            Expr::cust_with_values(
+                "coalesce((nullif(plainto_tsquery('simple', $1)::text, '') || ':*')::tsquery, plainto_tsquery('simple', $2))",
+                [term.clone(), term.clone()],
+            )
+        })
+        .reduce(|query, term| Expr::cust_with_exprs("($1 && $2)", [query, term]))
+        .expect("search terms are not empty")
+}
+
+fn full_text_search(select: Select<Entity>, terms: &[String]) -> Select<Entity> {
+    let search_vector = || SimpleExpr::from(Expr::col((Entity, Alias::new("search_vector"))));
+
+    select
+        .filter(Expr::cust_with_exprs("$1 @@ $2", [search_vector(), ts_query(terms)]))
+        .expr_as(
+            Expr::cust_with_exprs("ts_rank($1, $2)", [search_vector(), ts_query(terms)]),
+            "rank",
+        )
+        .expr_as(
+            Expr::cust_with_exprs(
+                "ts_headline('simple', $1, $2, 'StartSel=<mark>, StopSel=</mark>, HighlightAll=true')",
+                [Expr::col((Entity, Column::Title)).into(), ts_query(terms)],
+            ),
+            "highlight",
+        )
+}
+
+// SQLite: 단어마다 title LIKE '%단어%' 조건을 추가하고
+// title 또는 title 안의 단어가 검색어로 시작하면 1, 중간에서 일치하면 0.5를 더한 평균을 rank로 사용
+// -- highlight는 조회 후 search_products에서 채운다.
+fn like_search(select: Select<Entity>, terms: &[String]) -> Select<Entity> {
+    let mut select = select;
+    let mut rank = SimpleExpr::from(0.0);
+
+    for term in terms {
+        select = select.filter(Column::Title.contains(term));
+
+        let word_start = Expr::col((Entity, Column::Title))
+            .like(format!("{}%", term))
+            .or(Expr::col((Entity, Column::Title)).like(format!("% {}%", term)));
+        rank = rank.add(Expr::case(word_start, 1.0).finally(0.5));
+    }
+
+    select
+        .expr_as(rank.div(terms.len() as f64), "rank")
+        .expr_as(Expr::col((Entity, Column::Title)), "highlight")
+}
+
+// 검색어를 포함하는 단어를 <mark></mark>로 감싼다. (SQLite, ts_headline과 같은 형식)
+fn highlight(title: &str, terms: &[String]) -> String {
+    let mut result = String::with_capacity(title.len());
+    let mut rest = title;
+
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c.is_alphanumeric() != rest.starts_with(char::is_alphanumeric))
+            .unwrap_or(rest.len());
+        let (part, tail) = rest.split_at(end);
+
+        let lowercase = part.to_lowercase();
+        if terms.iter().any(|term| lowercase.contains(term.as_str())) {
+            result.push_str("<mark>");
+            result.push_str(part);
+            result.push_str("</mark>");
+        } else {
+            result.push_str(part);
+        }
+        rest = tail;
+    }
+
+    result
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 201, description = "Product created", body = Model,
+            headers(
+                ("location" = String, description = "URL of the created product")
+            )
+        ),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 409, description = "Category does not exist", body = ErrorResponse),
+        (status = 422, description = "Missing or invalid product fields", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn create_product_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
+) -> Result<(StatusCode, [(HeaderName, String); 1], Json<Model>), AppError> {
+    let Json(created) = post_product(State(conn), auth_user, ValidatedJson(product)).await?;
+    let location = format!("/api/v1/products/{}", created.id);
+
+    Ok((StatusCode::CREATED, [(header::LOCATION, location)], Json(created)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/products/{id}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 200, description = "Product replaced", body = Model),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 422, description = "Missing or invalid product fields", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn replace_product_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
+) -> Result<Json<Model>, AppError> {
+    require_all_fields(&product)?;
+
+    update_product(&conn, &auth_user, id, product).await.map(Json)
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/products/{id}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 200, description = "Product updated", body = Model),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 422, description = "Invalid product fields", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn patch_product_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    ValidatedJson(product): ValidatedJson<UpsertModel>,
+) -> Result<Json<Model>, AppError> {
+    update_product(&conn, &auth_user, id, product).await.map(Json)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/products/{id}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 204, description = "Product deleted"),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn delete_product_by_id_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    delete_product_by_id(&conn, &auth_user, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/{id}/restore",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Product restored", body = Model),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 409, description = "Product is not deleted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn restore_product_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<Json<Model>, AppError> {
+    let txn = conn.begin().await?;
+
+    let product = Entity::find_by_id(id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Product not found".into()))?;
+
+    if product.deleted_at.is_none() {
+        return Err(AppError::Conflict("Product is not deleted".into()));
+    }
+
+    let mut active_product: ActiveModel = product.clone().into();
+    active_product.deleted_at = ActiveValue::Set(None);
+    let restored_product = active_product.update(&txn).await?;
+
+    AuditEntry::restored(AUDIT_ENTITY, id, &product, &restored_product)
+        .write(&txn, Some(&auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(restored_product))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/price-history",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Price changes of the product, newest first", body = [price_history::Model]),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn price_history_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<price_history::Model>>, AppError> {
+    Entity::find_by_id(id)
+        .filter(Column::DeletedAt.is_null())
+        .one(&conn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Product not found".into()))?;
+
+    let history = price_history::Entity::find()
+        .filter(price_history::Column::ProductId.eq(id))
+        .order_by(price_history::Column::Id, Order::Desc)
+        .all(&conn)
+        .await?;
+
+    Ok(Json(history))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BulkDeleteParams {
+    /// 제목에 포함된 문자열
+    title: Option<String>,
+    /// 카테고리 (정확히 일치)
+    category: Option<String>,
+    /// 최소 가격 (inclusive)
+    #[param(value_type = Option<String>)]
+    #[serde(default, deserialize_with = "money::optional_amount::deserialize")]
+    price_gte: Option<Decimal>,
+    /// 최대 가격 (inclusive)
+    #[param(value_type = Option<String>)]
+    #[serde(default, deserialize_with = "money::optional_amount::deserialize")]
+    price_lte: Option<Decimal>,
+    /// true이면 삭제하지 않고 대상 목록만 반환
+    #[serde(default)]
+    dry_run: bool,
+    /// 삭제 대상 건수 확인 값: 실제 대상 건수와 일치해야 삭제된다 (dry_run 결과의 matched 값)
+    confirm: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct BulkDeleteResponse {
+    pub dry_run: bool,
+    // 필터 조건에 맞는 product 수
+    #[schema(example = 3)]
+    pub matched: u64,
+    // 실제 soft delete된 product 수 (dry_run이면 0)
+    #[schema(example = 3)]
+    pub deleted: u64,
+    pub products: Vec<Model>,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/products",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(BulkDeleteParams),
+    responses(
+        (status = 200, description = "Dry run preview or deleted products", body = BulkDeleteResponse),
+        (status = 400, description = "No filter, a blank title/category filter, or confirm not provided", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 409, description = "confirm does not match the number of affected products", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn bulk_delete_products_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Query(params): Query<BulkDeleteParams>,
+) -> Result<Json<BulkDeleteResponse>, AppError> {
+    bulk_delete_products(&conn, &auth_user, params).await.map(Json)
+}
+
+// DELETE /api/v1/products?category=Electronics&price_lte=100&dry_run=true
+// DELETE /api/v1/products?category=Electronics&price_lte=100&confirm=3
+pub async fn bulk_delete_products(
+    conn: &DatabaseConnection,
+    auth_user: &AuthUser,
+    params: BulkDeleteParams,
+) -> Result<BulkDeleteResponse, AppError> {
+    // 빈 문자열 필터는 모든 row에 일치하므로 필터로 인정하지 않는다. (?title=&confirm=N)
+    for (name, value) in [("title", &params.title), ("category", &params.category)] {
+        if value.as_deref().is_some_and(|value| value.trim().is_empty()) {
+            return Err(AppError::BadRequest(format!("{} filter must not be blank", name)));
+        }
+    }
+
+    let mut condition = Condition::all();
+
     if let Some(title) = params.title {
         condition = condition.add(Column::Title.contains(title));
     }
+    if let Some(category) = params.category {
+        condition = condition.add(Column::Category.eq(category));
+    }
+    if let Some(price_gte) = params.price_gte {
+        condition = condition.add(Column::Price.gte(price_gte));
+    }
+    if let Some(price_lte) = params.price_lte {
+        condition = condition.add(Column::Price.lte(price_lte));
+    }
 
-    if let Some(price) = params.price {
-        condition = condition.add(Column::Price.eq(price));
+    // 필터 없이 전체 삭제되는 것을 방지
+    if condition.is_empty() {
+        return Err(AppError::BadRequest("At least one filter is required for bulk delete".into()));
     }
 
-    if let Some(category) = params.category {
-        condition = condition.add(Column::Category.contains(category));
+    if !params.dry_run && params.confirm.is_none() {
+        return Err(AppError::BadRequest(
+            "confirm (number of products to delete) is required unless dry_run=true".into(),
+        ));
     }
 
-    let product = match Entity::find().filter(condition).one(&conn).await {
-        Ok(product) => product.ok_or(AppError::new(
-            StatusCode::NOT_FOUND,
-            "Product not found"
-        ))?,
-        Err(_) => return Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,  
-            "Database error"
-        )),
-    };
+    let txn = conn.begin().await?;
+
+    let products = Entity::find()
+        .filter(condition)
+        .filter(Column::DeletedAt.is_null())
+        .order_by(Column::Id, Order::Asc)
+        .lock_exclusive()
+        .all(&txn)
+        .await?;
+    let matched = products.len() as u64;
 
-    match product.delete(&conn).await {
-        Ok(_) => Ok(Json("Product deleted")),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+    if params.dry_run {
+        txn.rollback().await?;
+        return Ok(BulkDeleteResponse { dry_run: true, matched, deleted: 0, products });
     }
-}
\ No newline at end of file
+
+    if params.confirm != Some(matched) {
+        txn.rollback().await?;
+        return Err(AppError::Conflict(format!(
+            "confirm does not match: {} products match the filter",
+            matched
+        )));
+    }
+
+    let ids: Vec<i32> = products.iter().map(|product| product.id).collect();
+    let deleted = soft_delete(&txn, Condition::all().add(Column::Id.is_in(ids))).await?;
+
+    for product in &products {
+        AuditEntry::deleted(AUDIT_ENTITY, product.id, product)
+            .write(&txn, Some(auth_user))
+            .await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(BulkDeleteResponse {
+        dry_run: false,
+        matched,
+        deleted,
+        products,
+    })
+}
@@ -0,0 +1,589 @@
+use std::{io::Cursor, ops::Range, sync::Arc};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Multipart, Path, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::Utc;
+use image::{DynamicImage, ImageError, ImageFormat, ImageReader, Limits};
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection,
+    EntityTrait, ModelTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::{
+    api::inventory,
+    config::{Config, StorageConfig},
+    entities::product_image::{self, Entity, Model},
+    storage::Storage,
+    utils::app_error::{AppError, FieldError},
+    utils::audit::AuditEntry,
+    utils::auth_user::AuthUser,
+};
+
+pub const AUDIT_ENTITY: &str = "product_image";
+
+// 이미지 id마다 내용이 바뀌지 않으므로 (다시 올리면 새 id) 오래 캐시해도 된다.
+// -- 인증이 필요한 API이므로 공유 캐시에는 저장하지 않는다.
+const CACHE_CONTROL: &str = "private, max-age=31536000, immutable";
+
+#[derive(Serialize, ToSchema)]
+pub struct ImageResponse {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = 1)]
+    pub product_id: i32,
+    #[schema(example = "laptop.jpg")]
+    pub file_name: Option<String>,
+    #[schema(example = "image/jpeg")]
+    pub content_type: String,
+    #[schema(example = 204800)]
+    pub byte_size: i64,
+    #[schema(example = 1600)]
+    pub width: i32,
+    #[schema(example = 1200)]
+    pub height: i32,
+    #[schema(example = "/api/v1/products/1/images/1/content")]
+    pub url: String,
+    #[schema(example = "/api/v1/products/1/images/1/thumbnail")]
+    pub thumbnail_url: String,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<Model> for ImageResponse {
+    fn from(image: Model) -> Self {
+        let location = image_location(image.product_id, image.id);
+        Self {
+            id: image.id,
+            product_id: image.product_id,
+            file_name: image.file_name,
+            content_type: image.content_type,
+            byte_size: image.byte_size,
+            width: image.width,
+            height: image.height,
+            url: format!("{}/content", location),
+            thumbnail_url: format!("{}/thumbnail", location),
+            created_at: image.created_at,
+        }
+    }
+}
+
+// swagger 문서용 multipart 요청 본문
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImageUpload {
+    // JPEG, PNG, GIF 또는 WebP 이미지 (형식은 파일 내용으로 판단)
+    #[schema(value_type = String, format = Binary)]
+    file: Vec<u8>,
+}
+
+fn image_location(product_id: i32, image_id: i32) -> String {
+    format!("/api/v1/products/{}/images/{}", product_id, image_id)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/{id}/images",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    request_body(content = ImageUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Image stored with a generated thumbnail", body = ImageResponse,
+            headers(
+                ("location" = String, description = "URL of the created image")
+            )
+        ),
+        (status = 400, description = "Missing file field", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 413, description = "Image exceeds storage.max_image_bytes", body = ErrorResponse),
+        (status = 415, description = "Not a JPEG, PNG, GIF or WebP image", body = ErrorResponse),
+        (status = 422, description = "Corrupt image or dimensions over storage.max_image_dimension", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn upload_image_handler(
+    State(conn): State<DatabaseConnection>,
+    State(config): State<Arc<Config>>,
+    State(storage): State<Arc<dyn Storage>>,
+    auth_user: AuthUser,
+    Path(product_id): Path<i32>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, [(HeaderName, String); 1], Json<ImageResponse>), AppError> {
+    inventory::ensure_product(&conn, product_id).await?;
+
+    let mut field = loop {
+        match multipart.next_field().await? {
+            Some(field) if field.name() == Some("file") => break field,
+            Some(_) => continue,
+            None => return Err(AppError::BadRequest("Multipart field 'file' is required".into())),
+        }
+    };
+    let file_name = field.file_name().map(|name| name.chars().take(255).collect::<String>());
+
+    // storage.max_image_bytes를 넘으면 끝까지 읽지 않고 중단
+    let max_bytes = config.storage.max_image_bytes;
+    let mut data = Vec::new();
+    while let Some(chunk) = field.chunk().await? {
+        if data.len() + chunk.len() > max_bytes {
+            return Err(AppError::PayloadTooLarge(format!("Image exceeds {} bytes", max_bytes)));
+        }
+        data.extend_from_slice(&chunk);
+    }
+
+    // 클라이언트가 보낸 Content-Type은 믿지 않고 파일 내용으로 형식을 판단한다.
+    let format = sniff_format(&data)?;
+    let storage_config = config.storage.clone();
+    let data = Bytes::from(data);
+    let processed = {
+        let data = data.clone();
+        tokio::task::spawn_blocking(move || process_image(&data, format, &storage_config))
+            .await
+            .map_err(|err| {
+                error!("Thumbnail task failed: {:?}", err);
+                AppError::Internal("Error processing image".into())
+            })??
+    };
+
+    let name = uuid::Uuid::new_v4();
+    let extension = format.extensions_str()[0];
+    let storage_key = format!("products/{}/{}.{}", product_id, name, extension);
+    let thumbnail_key = format!("products/{}/{}_thumb.{}", product_id, name, processed.thumbnail_extension);
+    let content_type = format.to_mime_type();
+
+    storage.put(&storage_key, data.clone(), content_type).await?;
+    if let Err(err) = storage
+        .put(&thumbnail_key, processed.thumbnail.clone(), processed.thumbnail_content_type)
+        .await
+    {
+        remove_files(storage.as_ref(), &[&storage_key]).await;
+        return Err(err.into());
+    }
+
+    let inserted = async {
+        let txn = conn.begin().await?;
+        let inserted = product_image::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            file_name: ActiveValue::Set(file_name),
+            content_type: ActiveValue::Set(content_type.to_string()),
+            byte_size: ActiveValue::Set(data.len() as i64),
+            width: ActiveValue::Set(processed.width as i32),
+            height: ActiveValue::Set(processed.height as i32),
+            checksum: ActiveValue::Set(format!("{:x}", Sha256::digest(&data))),
+            storage_key: ActiveValue::Set(storage_key.clone()),
+            thumbnail_key: ActiveValue::Set(thumbnail_key.clone()),
+            thumbnail_content_type: ActiveValue::Set(processed.thumbnail_content_type.to_string()),
+            thumbnail_byte_size: ActiveValue::Set(processed.thumbnail.len() as i64),
+            created_at: ActiveValue::Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        AuditEntry::created(AUDIT_ENTITY, inserted.id, &inserted)
+            .write(&txn, Some(&auth_user))
+            .await?;
+        txn.commit().await?;
+        Ok::<_, AppError>(inserted)
+    }
+    .await;
+
+    // DB에 저장하지 못하면 이미 올린 파일을 지운다.
+    let inserted = match inserted {
+        Ok(inserted) => inserted,
+        Err(err) => {
+            remove_files(storage.as_ref(), &[&storage_key, &thumbnail_key]).await;
+            return Err(err);
+        }
+    };
+
+    let location = image_location(product_id, inserted.id);
+    Ok((StatusCode::CREATED, [(header::LOCATION, location)], Json(inserted.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/images",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Images of the product in upload order", body = Vec<ImageResponse>),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn list_images_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(product_id): Path<i32>,
+) -> Result<Json<Vec<ImageResponse>>, AppError> {
+    inventory::ensure_product(&conn, product_id).await?;
+
+    let images = Entity::find()
+        .filter(product_image::Column::ProductId.eq(product_id))
+        .order_by_asc(product_image::Column::Id)
+        .all(&conn)
+        .await?;
+
+    Ok(Json(images.into_iter().map(ImageResponse::from).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/images/{image_id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID"),
+        ("image_id" = i32, Path, description = "Image ID")
+    ),
+    responses(
+        (status = 200, description = "Image metadata", body = ImageResponse),
+        (status = 404, description = "Product or image not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn get_image_handler(
+    State(conn): State<DatabaseConnection>,
+    Path((product_id, image_id)): Path<(i32, i32)>,
+) -> Result<Json<ImageResponse>, AppError> {
+    let image = find_image(&conn, product_id, image_id).await?;
+    Ok(Json(image.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/images/{image_id}/content",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID"),
+        ("image_id" = i32, Path, description = "Image ID"),
+        ("range" = Option<String>, Header, description = "Single byte range, e.g. bytes=0-1023"),
+        ("if-none-match" = Option<String>, Header, description = "ETag from a previous response")
+    ),
+    responses(
+        (status = 200, description = "Original image",
+            content(("image/jpeg" = String), ("image/png" = String), ("image/gif" = String), ("image/webp" = String))
+        ),
+        (status = 206, description = "Requested byte range of the image"),
+        (status = 304, description = "Image matches If-None-Match"),
+        (status = 404, description = "Product or image not found", body = ErrorResponse),
+        (status = 416, description = "Range is outside the image"),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn image_content_handler(
+    State(conn): State<DatabaseConnection>,
+    State(storage): State<Arc<dyn Storage>>,
+    Path((product_id, image_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let image = find_image(&conn, product_id, image_id).await?;
+    let file = StoredFile {
+        key: &image.storage_key,
+        content_type: &image.content_type,
+        size: image.byte_size as u64,
+        etag: format!("\"{}\"", image.checksum),
+        last_modified: image.created_at,
+    };
+    serve(storage.as_ref(), &headers, file).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/{id}/images/{image_id}/thumbnail",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID"),
+        ("image_id" = i32, Path, description = "Image ID"),
+        ("range" = Option<String>, Header, description = "Single byte range, e.g. bytes=0-1023"),
+        ("if-none-match" = Option<String>, Header, description = "ETag from a previous response")
+    ),
+    responses(
+        (status = 200, description = "Thumbnail (JPEG for JPEG originals, PNG otherwise)",
+            content(("image/jpeg" = String), ("image/png" = String))
+        ),
+        (status = 206, description = "Requested byte range of the thumbnail"),
+        (status = 304, description = "Thumbnail matches If-None-Match"),
+        (status = 404, description = "Product or image not found", body = ErrorResponse),
+        (status = 416, description = "Range is outside the thumbnail"),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn image_thumbnail_handler(
+    State(conn): State<DatabaseConnection>,
+    State(storage): State<Arc<dyn Storage>>,
+    Path((product_id, image_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let image = find_image(&conn, product_id, image_id).await?;
+    let file = StoredFile {
+        key: &image.thumbnail_key,
+        content_type: &image.thumbnail_content_type,
+        size: image.thumbnail_byte_size as u64,
+        etag: format!("\"{}-thumb\"", image.checksum),
+        last_modified: image.created_at,
+    };
+    serve(storage.as_ref(), &headers, file).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/products/{id}/images/{image_id}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "Product ID"),
+        ("image_id" = i32, Path, description = "Image ID")
+    ),
+    responses(
+        (status = 204, description = "Image and its thumbnail deleted"),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Product or image not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn delete_image_handler(
+    State(conn): State<DatabaseConnection>,
+    State(storage): State<Arc<dyn Storage>>,
+    auth_user: AuthUser,
+    Path((product_id, image_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    let txn = conn.begin().await?;
+    let image = find_image(&txn, product_id, image_id).await?;
+    image.clone().delete(&txn).await?;
+    AuditEntry::deleted(AUDIT_ENTITY, image.id, &image)
+        .write(&txn, Some(&auth_user))
+        .await?;
+    txn.commit().await?;
+
+    // 파일 삭제에 실패해도 DB에서는 이미 지웠으므로 로그만 남긴다.
+    remove_files(storage.as_ref(), &[&image.storage_key, &image.thumbnail_key]).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 삭제되지 않은 product의 이미지만 조회
+async fn find_image<C: sea_orm::ConnectionTrait>(
+    conn: &C,
+    product_id: i32,
+    image_id: i32,
+) -> Result<Model, AppError> {
+    inventory::ensure_product(conn, product_id).await?;
+    Entity::find_by_id(image_id)
+        .filter(product_image::Column::ProductId.eq(product_id))
+        .one(conn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Image not found".into()))
+}
+
+async fn remove_files(storage: &dyn Storage, keys: &[&str]) {
+    for key in keys {
+        if let Err(err) = storage.delete(key).await {
+            error!("Failed to delete stored file {}: {:?}", key, err);
+        }
+    }
+}
+
+fn sniff_format(data: &[u8]) -> Result<ImageFormat, AppError> {
+    match image::guess_format(data) {
+        Ok(format @ (ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::Gif | ImageFormat::WebP)) => Ok(format),
+        _ => Err(AppError::UnsupportedMediaType(
+            "Only JPEG, PNG, GIF and WebP images are supported".into(),
+        )),
+    }
+}
+
+struct ProcessedImage {
+    width: u32,
+    height: u32,
+    thumbnail: Bytes,
+    thumbnail_content_type: &'static str,
+    thumbnail_extension: &'static str,
+}
+
+// 이미지를 decode해서 크기를 확인하고 썸네일을 만든다. (CPU 작업이므로 spawn_blocking에서 실행)
+// -- 썸네일은 JPEG 원본이면 JPEG, 그 외 (투명도가 있을 수 있음)는 PNG로 저장한다.
+fn process_image(data: &[u8], format: ImageFormat, config: &StorageConfig) -> Result<ProcessedImage, AppError> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(config.max_image_dimension);
+    limits.max_image_height = Some(config.max_image_dimension);
+
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|err| {
+        let message = match err {
+            ImageError::Limits(_) => format!(
+                "image dimensions must not exceed {}x{}",
+                config.max_image_dimension, config.max_image_dimension
+            ),
+            _ => "is not a valid image".to_string(),
+        };
+        AppError::Validation("Invalid image".into(), vec![FieldError::new("file", message)])
+    })?;
+
+    let thumbnail = image.thumbnail(config.thumbnail_size, config.thumbnail_size);
+    let (thumbnail, thumbnail_format) = match format {
+        ImageFormat::Jpeg => (DynamicImage::ImageRgb8(thumbnail.to_rgb8()), ImageFormat::Jpeg),
+        _ => (thumbnail, ImageFormat::Png),
+    };
+    let mut encoded = Cursor::new(Vec::new());
+    thumbnail.write_to(&mut encoded, thumbnail_format).map_err(|err| {
+        error!("Thumbnail encoding failed: {:?}", err);
+        AppError::Internal("Error processing image".into())
+    })?;
+
+    Ok(ProcessedImage {
+        width: image.width(),
+        height: image.height(),
+        thumbnail: Bytes::from(encoded.into_inner()),
+        thumbnail_content_type: thumbnail_format.to_mime_type(),
+        thumbnail_extension: thumbnail_format.extensions_str()[0],
+    })
+}
+
+struct StoredFile<'a> {
+    key: &'a str,
+    content_type: &'a str,
+    size: u64,
+    etag: String,
+    last_modified: DateTimeWithTimeZone,
+}
+
+// 조건부 요청 (If-None-Match)과 단일 Range 요청을 처리해서 storage의 파일을 응답한다.
+// -- tower_http::services::ServeDir과 같은 방식이지만 storage backend와 상관없이 동작한다.
+async fn serve(storage: &dyn Storage, headers: &HeaderMap, file: StoredFile<'_>) -> Result<Response, AppError> {
+    let last_modified = file
+        .last_modified
+        .with_timezone(&Utc)
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string();
+    let common = [
+        (header::ETAG, file.etag.clone()),
+        (header::LAST_MODIFIED, last_modified),
+        (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+        (header::ACCEPT_RANGES, "bytes".to_string()),
+    ];
+
+    if let Some(if_none_match) = header_str(headers, &header::IF_NONE_MATCH) {
+        if etag_matches(if_none_match, &file.etag) {
+            return Ok((StatusCode::NOT_MODIFIED, common).into_response());
+        }
+    }
+
+    // If-Range의 ETag가 다르면 (파일이 바뀌었으면) Range를 무시하고 전체를 보낸다.
+    let range = header_str(headers, &header::RANGE).filter(|_| {
+        header_str(headers, &header::IF_RANGE).is_none_or(|if_range| if_range == file.etag)
+    });
+    let range = match range.and_then(|range| parse_range(range, file.size)) {
+        Some(Ok(range)) => Some(range),
+        Some(Err(())) => {
+            let content_range = format!("bytes */{}", file.size);
+            return Ok((StatusCode::RANGE_NOT_SATISFIABLE, common, [(header::CONTENT_RANGE, content_range)])
+                .into_response());
+        }
+        None => None,
+    };
+
+    let stream = storage.get(file.key, range.clone()).await?;
+    let mut response = (common, Body::from_stream(stream)).into_response();
+    let response_headers = response.headers_mut();
+    response_headers.insert(header::CONTENT_TYPE, header_value(file.content_type)?);
+    match range {
+        Some(range) => {
+            *response.status_mut() = StatusCode::PARTIAL_CONTENT;
+            let response_headers = response.headers_mut();
+            response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(range.end - range.start));
+            let content_range = format!("bytes {}-{}/{}", range.start, range.end - 1, file.size);
+            response_headers.insert(header::CONTENT_RANGE, header_value(&content_range)?);
+        }
+        None => {
+            response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(file.size));
+        }
+    }
+    Ok(response)
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &HeaderName) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn header_value(value: &str) -> Result<HeaderValue, AppError> {
+    HeaderValue::from_str(value).map_err(|_| AppError::Internal("Invalid header value".into()))
+}
+
+// If-None-Match는 weak 비교 (W/ 접두사 무시), "*"는 모든 ETag와 일치
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match
+        .split(',')
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+// Range: bytes=<start>-<end> | bytes=<start>- | bytes=-<suffix>
+// -- 해석할 수 없거나 여러 범위를 요청하면 None (Range를 무시하고 전체를 보낸다)
+// -- 파일 범위를 벗어나면 Some(Err(())) (416)
+fn parse_range(value: &str, size: u64) -> Option<Result<Range<u64>, ()>> {
+    let spec = value.strip_prefix("bytes=")?.trim();
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    let range = match (start.is_empty(), end.is_empty()) {
+        // 마지막 suffix bytes
+        (true, false) => {
+            let suffix: u64 = end.parse().ok()?;
+            if suffix == 0 {
+                return Some(Err(()));
+            }
+            size.saturating_sub(suffix)..size
+        }
+        (false, _) => {
+            let start: u64 = start.parse().ok()?;
+            let end = match end.is_empty() {
+                true => size,
+                false => {
+                    let end: u64 = end.parse().ok()?;
+                    if end < start {
+                        return None;
+                    }
+                    end.saturating_add(1).min(size)
+                }
+            };
+            start..end
+        }
+        (true, true) => return None,
+    };
+
+    if range.start >= size || range.is_empty() {
+        return Some(Err(()));
+    }
+    Some(Ok(range))
+}
@@ -0,0 +1,903 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{multipart::Field, Multipart, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use futures_util::stream;
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection,
+    DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tracing::error;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::{
+    api,
+    entities::{category, product},
+    utils::app_error::{AppError, FieldError},
+    utils::audit::AuditEntry,
+    utils::auth_user::AuthUser,
+    utils::money::{self, check_scale, currency_code, non_negative_amount, DEFAULT_CURRENCY},
+    utils::validated_json::{field_errors, not_blank},
+};
+
+// 응답에 포함할 실패 행의 최대 개수 (failed에는 모든 실패 행이 집계된다)
+const MAX_REPORTED_ERRORS: usize = 100;
+// 한 레코드(줄)의 최대 크기 (닫히지 않은 따옴표 등으로 버퍼가 끝없이 커지는 것을 막는다)
+const MAX_LINE_BYTES: usize = 64 * 1024;
+// export 시 한 번에 조회하는 product 수
+const EXPORT_BATCH_SIZE: u64 = 500;
+// CSV header (export 순서). import는 header 이름으로 컬럼을 찾으며 모르는 컬럼(id 등)은 무시한다.
+const CSV_COLUMNS: [&str; 7] = ["id", "title", "price", "currency", "category", "created_at", "updated_at"];
+const REQUIRED_COLUMNS: [&str; 3] = ["title", "price", "category"];
+// category CSV header (export 순서, parent는 상위 category 이름)
+const CATEGORY_CSV_COLUMNS: [&str; 5] = ["id", "name", "parent", "created_at", "updated_at"];
+const CATEGORY_REQUIRED_COLUMNS: [&str; 1] = ["name"];
+// 스프레드시트가 수식으로 해석하는 셀의 첫 문자 (CSV formula injection)
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Csv,
+    Ndjson,
+}
+
+impl Format {
+    fn parse(value: &str) -> Result<Self, AppError> {
+        match value {
+            "csv" => Ok(Format::Csv),
+            "ndjson" | "jsonl" => Ok(Format::Ndjson),
+            _ => Err(AppError::BadRequest(format!("Unsupported format: {} (use csv or ndjson)", value))),
+        }
+    }
+
+    // format query가 없으면 업로드한 파일의 Content-Type, 확장자로 판단
+    fn detect(field: &Field<'_>) -> Option<Self> {
+        let content_type = field.content_type().unwrap_or_default();
+        let file_name = field.file_name().unwrap_or_default().to_ascii_lowercase();
+
+        if content_type.starts_with("text/csv") || file_name.ends_with(".csv") {
+            Some(Format::Csv)
+        } else if content_type.starts_with("application/x-ndjson")
+            || content_type.starts_with("application/jsonl")
+            || file_name.ends_with(".ndjson")
+            || file_name.ends_with(".jsonl")
+        {
+            Some(Format::Ndjson)
+        } else {
+            None
+        }
+    }
+}
+
+// import할 대상 (product import와 category import는 파일 처리 과정을 공유한다)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    Products,
+    Categories,
+}
+
+impl Target {
+    fn required_columns(self) -> &'static [&'static str] {
+        match self {
+            Target::Products => &REQUIRED_COLUMNS,
+            Target::Categories => &CATEGORY_REQUIRED_COLUMNS,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    // 실패한 행이 하나라도 있으면 아무것도 저장하지 않는다.
+    #[default]
+    AllOrNothing,
+    // 검증을 통과한 행만 저장한다.
+    BestEffort,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportParams {
+    /// csv 또는 ndjson (생략하면 파일의 Content-Type 또는 확장자로 판단)
+    format: Option<String>,
+    /// all_or_nothing (기본) 또는 best_effort
+    #[serde(default)]
+    #[param(inline)]
+    mode: ImportMode,
+    /// false이면 없는 category를 만들지 않고 해당 행을 실패로 처리 (기본 true)
+    #[serde(default = "default_true")]
+    create_categories: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryImportParams {
+    /// csv 또는 ndjson (생략하면 파일의 Content-Type 또는 확장자로 판단)
+    format: Option<String>,
+    /// all_or_nothing (기본) 또는 best_effort
+    #[serde(default)]
+    #[param(inline)]
+    mode: ImportMode,
+}
+
+// swagger 문서용 multipart 요청 본문
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImportUpload {
+    // CSV (header 필수: title, price, category, currency는 선택) 또는 NDJSON 파일
+    #[schema(value_type = String, format = Binary)]
+    file: Vec<u8>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ImportReport {
+    pub mode: ImportMode,
+    // 처리한 데이터 행 수 (CSV header와 빈 줄 제외)
+    pub rows: u64,
+    // 저장한 product (category import는 category) 수
+    pub imported: u64,
+    pub failed: u64,
+    // product import에서 자동으로 만든 category
+    pub created_categories: Vec<String>,
+    // 실패한 행 (최대 100개)
+    pub errors: Vec<RowError>,
+    // all_or_nothing에서 실패한 행이 있어 모두 취소되었으면 false
+    pub committed: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RowError {
+    // 파일의 레코드 번호 (1부터, CSV header는 1번)
+    #[schema(example = 3)]
+    pub line: u64,
+    #[schema(value_type = Vec<crate::swagger::FieldErrorResponse>)]
+    pub errors: Vec<FieldError>,
+}
+
+// import 한 행 (CSV는 header 이름, NDJSON은 key 이름으로 매핑)
+#[derive(Deserialize, Validate)]
+struct ImportRow {
+    #[validate(length(min = 1, max = 200), custom(function = "not_blank"))]
+    title: String,
+    #[serde(deserialize_with = "money::amount::deserialize")]
+    #[validate(custom(function = "non_negative_amount"))]
+    price: Decimal,
+    #[validate(custom(function = "currency_code"))]
+    currency: Option<String>,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    category: String,
+}
+
+// category import 한 행 (parent는 상위 category 이름, 파일의 앞쪽 행에서 만든 category도 사용 가능)
+#[derive(Deserialize, Validate)]
+struct CategoryImportRow {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    name: String,
+    #[validate(length(min = 1, max = 100))]
+    parent: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/products/import",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(ImportParams),
+    request_body(content = ImportUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Import finished (best_effort may report failed rows)", body = ImportReport),
+        (status = 400, description = "Missing file field, unknown format or malformed CSV header", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 413, description = "Upload exceeds server.max_upload_bytes", body = ErrorResponse),
+        (status = 422, description = "all_or_nothing import rolled back because some rows failed", body = ImportReport),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn import_products_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Query(params): Query<ImportParams>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<ImportReport>), AppError> {
+    import_file(
+        &conn,
+        &auth_user,
+        params.format.as_deref(),
+        params.mode,
+        Target::Products,
+        params.create_categories,
+        multipart,
+    )
+    .await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/categories/import",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(CategoryImportParams),
+    request_body(content = ImportUpload, content_type = "multipart/form-data",
+        description = "CSV (header required: name, parent is optional) or NDJSON file"
+    ),
+    responses(
+        (status = 200, description = "Import finished (best_effort may report failed rows)", body = ImportReport),
+        (status = 400, description = "Missing file field, unknown format or malformed CSV header", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 413, description = "Upload exceeds server.max_upload_bytes", body = ErrorResponse),
+        (status = 422, description = "all_or_nothing import rolled back because some rows failed", body = ImportReport),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn import_categories_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Query(params): Query<CategoryImportParams>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<ImportReport>), AppError> {
+    import_file(
+        &conn,
+        &auth_user,
+        params.format.as_deref(),
+        params.mode,
+        Target::Categories,
+        false,
+        multipart,
+    )
+    .await
+}
+
+// multipart의 'file' 필드를 읽으면서 한 transaction 안에서 target에 import한다.
+async fn import_file(
+    conn: &DatabaseConnection,
+    auth_user: &AuthUser,
+    format: Option<&str>,
+    mode: ImportMode,
+    target: Target,
+    create_categories: bool,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<ImportReport>), AppError> {
+    let mut field = loop {
+        match multipart.next_field().await? {
+            Some(field) if field.name() == Some("file") => break field,
+            Some(_) => continue,
+            None => return Err(AppError::BadRequest("Multipart field 'file' is required".into())),
+        }
+    };
+
+    let format = match format {
+        Some(format) => Format::parse(format)?,
+        None => Format::detect(&field).ok_or_else(|| {
+            AppError::BadRequest("Cannot determine file format; set format=csv or format=ndjson".into())
+        })?,
+    };
+
+    // 업로드를 끝까지 읽지 않고 chunk 단위로 레코드를 나눠서 바로 저장한다.
+    let txn = conn.begin().await?;
+    let mut importer = Importer::new(&txn, auth_user, format, target, mode, create_categories);
+    let mut lines = LineSplitter::new(format == Format::Csv);
+    while let Some(chunk) = field.chunk().await? {
+        for line in lines.push(&chunk)? {
+            importer.line(line).await?;
+        }
+    }
+    if let Some(line) = lines.finish() {
+        importer.line(line).await?;
+    }
+
+    let mut report = importer.report;
+    if report.mode == ImportMode::AllOrNothing && report.failed > 0 {
+        txn.rollback().await?;
+        report.imported = 0;
+        report.created_categories.clear();
+        report.committed = false;
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, Json(report)));
+    }
+    txn.commit().await?;
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+struct Importer<'a> {
+    txn: &'a DatabaseTransaction,
+    auth_user: &'a AuthUser,
+    format: Format,
+    target: Target,
+    create_categories: bool,
+    // CSV header (첫 번째 레코드)
+    columns: Option<Vec<String>>,
+    // category 이름 -> 사용 가능 여부 (이미 확인한 category는 다시 조회하지 않는다)
+    categories: HashMap<String, Result<(), String>>,
+    line: u64,
+    report: ImportReport,
+}
+
+impl<'a> Importer<'a> {
+    fn new(
+        txn: &'a DatabaseTransaction,
+        auth_user: &'a AuthUser,
+        format: Format,
+        target: Target,
+        mode: ImportMode,
+        create_categories: bool,
+    ) -> Self {
+        Self {
+            txn,
+            auth_user,
+            format,
+            target,
+            create_categories,
+            columns: None,
+            categories: HashMap::new(),
+            line: 0,
+            report: ImportReport {
+                mode,
+                rows: 0,
+                imported: 0,
+                failed: 0,
+                created_categories: Vec::new(),
+                errors: Vec::new(),
+                committed: true,
+            },
+        }
+    }
+
+    async fn line(&mut self, line: Vec<u8>) -> Result<(), AppError> {
+        self.line += 1;
+        let line = strip_line(&line, self.line == 1);
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return Ok(());
+        }
+
+        let object = match self.format {
+            Format::Csv => {
+                let record = parse_csv(line)
+                    .map_err(|err| AppError::BadRequest(format!("Invalid CSV at line {}: {}", self.line, err)));
+                let Some(columns) = &self.columns else {
+                    return self.header(record?);
+                };
+                match record {
+                    Ok(record) => Ok(csv_object(columns, &record)),
+                    Err(AppError::BadRequest(message)) => Err(vec![FieldError::new("line", message)]),
+                    Err(err) => return Err(err),
+                }
+            }
+            Format::Ndjson => match serde_json::from_slice::<Value>(line) {
+                Ok(Value::Object(object)) => Ok(object),
+                Ok(_) => Err(vec![FieldError::new("line", "must be a JSON object")]),
+                Err(err) => Err(vec![FieldError::new("line", format!("invalid JSON: {}", err))]),
+            },
+        };
+
+        self.report.rows += 1;
+        let result = match object {
+            Ok(object) => self.import(object).await?,
+            Err(errors) => Err(errors),
+        };
+        match result {
+            Ok(()) => self.report.imported += 1,
+            Err(errors) => {
+                self.report.failed += 1;
+                if self.report.errors.len() < MAX_REPORTED_ERRORS {
+                    self.report.errors.push(RowError { line: self.line, errors });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn header(&mut self, record: Vec<String>) -> Result<(), AppError> {
+        let columns: Vec<String> = record.iter().map(|name| name.trim().to_ascii_lowercase()).collect();
+        let missing: Vec<&str> = self
+            .target
+            .required_columns()
+            .iter()
+            .copied()
+            .filter(|required| !columns.iter().any(|column| column == required))
+            .collect();
+        if !missing.is_empty() {
+            return Err(AppError::BadRequest(format!(
+                "CSV header is missing required columns: {}",
+                missing.join(", ")
+            )));
+        }
+        self.columns = Some(columns);
+        Ok(())
+    }
+
+    // 한 행을 검증하고 저장한다. 행의 문제는 안쪽 Err(필드 오류), DB 오류 등은 바깥 Err로 반환한다.
+    async fn import(&mut self, object: Map<String, Value>) -> Result<Result<(), Vec<FieldError>>, AppError> {
+        let missing: Vec<FieldError> = self
+            .target
+            .required_columns()
+            .iter()
+            .copied()
+            .filter(|column| object.get(*column).is_none_or(Value::is_null))
+            .map(|column| FieldError::new(column, "is required"))
+            .collect();
+        if !missing.is_empty() {
+            return Ok(Err(missing));
+        }
+
+        match self.target {
+            Target::Products => self.import_product(object).await,
+            Target::Categories => self.import_category(object).await,
+        }
+    }
+
+    async fn import_product(&mut self, object: Map<String, Value>) -> Result<Result<(), Vec<FieldError>>, AppError> {
+        let row: ImportRow = match serde_json::from_value(Value::Object(object)) {
+            Ok(row) => row,
+            Err(err) => return Ok(Err(vec![FieldError::new("line", err.to_string())])),
+        };
+        if let Err(errors) = row.validate() {
+            return Ok(Err(field_errors(&errors)));
+        }
+
+        let currency = row.currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string());
+        match check_scale("price", row.price, &currency) {
+            Ok(()) => {}
+            Err(AppError::Validation(_, errors)) => return Ok(Err(errors)),
+            Err(err) => return Err(err),
+        }
+        if let Err(message) = self.ensure_category(&row.category).await? {
+            return Ok(Err(vec![FieldError::new("category", message)]));
+        }
+
+        let inserted = product::ActiveModel {
+            title: ActiveValue::Set(row.title),
+            price: ActiveValue::Set(row.price),
+            currency: ActiveValue::Set(currency),
+            category: ActiveValue::Set(row.category),
+            ..Default::default()
+        }
+        .insert(self.txn)
+        .await?;
+        AuditEntry::created(api::product::AUDIT_ENTITY, inserted.id, &inserted)
+            .write(self.txn, Some(self.auth_user))
+            .await?;
+
+        Ok(Ok(()))
+    }
+
+    async fn import_category(&mut self, object: Map<String, Value>) -> Result<Result<(), Vec<FieldError>>, AppError> {
+        let row: CategoryImportRow = match serde_json::from_value(Value::Object(object)) {
+            Ok(row) => row,
+            Err(err) => return Ok(Err(vec![FieldError::new("line", err.to_string())])),
+        };
+        if let Err(errors) = row.validate() {
+            return Ok(Err(field_errors(&errors)));
+        }
+
+        // name은 unique이므로 삭제된 category와도 겹칠 수 없다.
+        let existing = category::Entity::find()
+            .filter(category::Column::Name.eq(&row.name))
+            .one(self.txn)
+            .await?;
+        if let Some(found) = existing {
+            let message = if found.deleted_at.is_some() { "category is deleted" } else { "category already exists" };
+            return Ok(Err(vec![FieldError::new("name", message)]));
+        }
+
+        let parent_id = match &row.parent {
+            Some(parent) => {
+                let parent = category::Entity::find()
+                    .filter(category::Column::Name.eq(parent))
+                    .filter(category::Column::DeletedAt.is_null())
+                    .one(self.txn)
+                    .await?;
+                match parent {
+                    Some(parent) => Some(parent.id),
+                    None => return Ok(Err(vec![FieldError::new("parent", "parent category does not exist")])),
+                }
+            }
+            None => None,
+        };
+
+        let created = category::ActiveModel {
+            name: ActiveValue::Set(row.name),
+            parent_id: ActiveValue::Set(parent_id),
+            ..Default::default()
+        }
+        .insert(self.txn)
+        .await?;
+        AuditEntry::created(api::category::AUDIT_ENTITY, created.id, &created)
+            .write(self.txn, Some(self.auth_user))
+            .await?;
+
+        Ok(Ok(()))
+    }
+
+    // category가 있는지 확인하고, 없으면 (create_categories=true인 경우) 최상위 category로 만든다.
+    async fn ensure_category(&mut self, name: &str) -> Result<Result<(), String>, AppError> {
+        if let Some(result) = self.categories.get(name) {
+            return Ok(result.clone());
+        }
+
+        let existing = category::Entity::find()
+            .filter(category::Column::Name.eq(name))
+            .one(self.txn)
+            .await?;
+        let result = match existing {
+            Some(found) if found.deleted_at.is_some() => Err("category is deleted".to_string()),
+            Some(_) => Ok(()),
+            None if !self.create_categories => Err("category does not exist".to_string()),
+            None => {
+                let created = category::ActiveModel {
+                    name: ActiveValue::Set(name.to_string()),
+                    ..Default::default()
+                }
+                .insert(self.txn)
+                .await?;
+                AuditEntry::created(api::category::AUDIT_ENTITY, created.id, &created)
+                    .write(self.txn, Some(self.auth_user))
+                    .await?;
+                self.report.created_categories.push(created.name);
+                Ok(())
+            }
+        };
+
+        self.categories.insert(name.to_string(), result.clone());
+        Ok(result)
+    }
+}
+
+// 스트림으로 받은 chunk를 레코드(줄) 단위로 나눈다.
+// -- CSV는 따옴표 안의 줄바꿈을 레코드 구분으로 보지 않는다. ("" escape는 두 번 토글되므로 상관없음)
+// -- NDJSON은 문자열 안에 줄바꿈이 올 수 없으므로 줄바꿈으로만 나눈다.
+struct LineSplitter {
+    buf: Vec<u8>,
+    scanned: usize,
+    quoted: bool,
+    in_quotes: bool,
+}
+
+impl LineSplitter {
+    fn new(quoted: bool) -> Self {
+        Self { buf: Vec::new(), scanned: 0, quoted, in_quotes: false }
+    }
+
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<Vec<u8>>, AppError> {
+        self.buf.extend_from_slice(chunk);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for i in self.scanned..self.buf.len() {
+            match self.buf[i] {
+                b'"' if self.quoted => self.in_quotes = !self.in_quotes,
+                b'\n' if !self.in_quotes => {
+                    lines.push(self.buf[start..i].to_vec());
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        self.buf.drain(..start);
+        self.scanned = self.buf.len();
+
+        if self.buf.len() > MAX_LINE_BYTES {
+            return Err(AppError::BadRequest(format!(
+                "A record exceeds {} bytes (unterminated quote?)",
+                MAX_LINE_BYTES
+            )));
+        }
+        Ok(lines)
+    }
+
+    // 마지막 줄바꿈 뒤에 남은 레코드
+    fn finish(self) -> Option<Vec<u8>> {
+        (!self.buf.is_empty()).then_some(self.buf)
+    }
+}
+
+// 줄 끝의 \r과 파일 맨 앞의 UTF-8 BOM (Excel에서 저장한 CSV)을 제거
+fn strip_line(line: &[u8], first: bool) -> &[u8] {
+    let line = if first { line.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(line) } else { line };
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+fn parse_csv(line: &[u8]) -> Result<Vec<String>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new().has_headers(false).from_reader(line);
+    match reader.records().next() {
+        Some(record) => Ok(record?.iter().map(str::to_string).collect()),
+        None => Ok(Vec::new()),
+    }
+}
+
+// CSV 행을 header 이름을 key로 하는 object로 변환 (빈 칸은 값이 없는 것으로 처리)
+fn csv_object(columns: &[String], record: &[String]) -> Map<String, Value> {
+    columns
+        .iter()
+        .zip(record)
+        .filter(|(_, value)| !value.trim().is_empty())
+        .map(|(column, value)| (column.clone(), Value::String(unescape_formula(value.trim()).to_string())))
+        .collect()
+}
+
+// 수식으로 시작하거나, 이미 '로 escape된 수식처럼 보이는 값 ("'=1")
+fn is_formula(value: &str) -> bool {
+    value.starts_with(FORMULA_PREFIXES)
+        || value.strip_prefix('\'').is_some_and(|rest| rest.starts_with(FORMULA_PREFIXES))
+}
+
+// export: 스프레드시트에서 수식으로 실행되지 않도록 앞에 '를 붙인다.
+fn escape_formula(value: &str) -> String {
+    if is_formula(value) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+// import: escape_formula가 붙인 '를 제거
+fn unescape_formula(value: &str) -> &str {
+    match value.strip_prefix('\'') {
+        Some(rest) if is_formula(rest) => rest,
+        _ => value,
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportParams {
+    /// csv (기본) 또는 ndjson
+    format: Option<String>,
+    /// category 이름이 정확히 일치하는 product만
+    category: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/products/export",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(ExportParams),
+    responses(
+        (status = 200, description = "All products that are not deleted, ordered by id and streamed as CSV or NDJSON (CSV cells starting with =, +, -, @, TAB or CR are prefixed with ')",
+            content(("text/csv" = String), ("application/x-ndjson" = String))
+        ),
+        (status = 400, description = "Unsupported format", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Products"
+)]
+pub async fn export_products_handler(
+    State(conn): State<DatabaseConnection>,
+    Query(params): Query<ExportParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = Format::parse(params.format.as_deref().unwrap_or("csv"))?;
+
+    let mut condition = Condition::all().add(product::Column::DeletedAt.is_null());
+    if let Some(category) = params.category {
+        condition = condition.add(product::Column::Category.eq(category));
+    }
+
+    let (content_type, file_name) = match format {
+        Format::Csv => ("text/csv; charset=utf-8", "products.csv"),
+        Format::Ndjson => ("application/x-ndjson", "products.ndjson"),
+    };
+    let headers = [
+        (header::CONTENT_TYPE, content_type.to_string()),
+        (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+    ];
+
+    Ok((headers, Body::from_stream(export_stream(conn, format, condition))))
+}
+
+struct ExportState {
+    conn: DatabaseConnection,
+    format: Format,
+    condition: Condition,
+    // keyset pagination: 마지막으로 보낸 product id
+    last_id: Option<i32>,
+    first: bool,
+    done: bool,
+}
+
+// product를 id 순으로 EXPORT_BATCH_SIZE개씩 조회하면서 바로 응답으로 보낸다. (전체를 메모리에 올리지 않음)
+// -- 응답을 보내기 시작한 뒤에는 status를 바꿀 수 없으므로 DB 오류는 스트림을 중단해서 알린다.
+fn export_stream(
+    conn: DatabaseConnection,
+    format: Format,
+    condition: Condition,
+) -> impl futures_util::Stream<Item = Result<Bytes, io::Error>> {
+    let state = ExportState { conn, format, condition, last_id: None, first: true, done: false };
+
+    stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+
+        let mut select = product::Entity::find()
+            .filter(state.condition.clone())
+            .order_by_asc(product::Column::Id)
+            .limit(EXPORT_BATCH_SIZE);
+        if let Some(last_id) = state.last_id {
+            select = select.filter(product::Column::Id.gt(last_id));
+        }
+
+        let products = match select.all(&state.conn).await {
+            Ok(products) => products,
+            Err(err) => {
+                error!("Product export failed: {:?}", err);
+                state.done = true;
+                return Some((Err(io::Error::other("product export failed")), state));
+            }
+        };
+
+        state.done = (products.len() as u64) < EXPORT_BATCH_SIZE;
+        state.last_id = products.last().map(|product| product.id).or(state.last_id);
+        let chunk = encode(state.format, &products, state.first);
+        state.first = false;
+        Some((chunk.map(Bytes::from), state))
+    })
+}
+
+fn encode(format: Format, products: &[product::Model], header: bool) -> Result<Vec<u8>, io::Error> {
+    match format {
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            if header {
+                writer.write_record(CSV_COLUMNS)?;
+            }
+            for product in products {
+                writer.write_record([
+                    product.id.to_string(),
+                    escape_formula(&product.title),
+                    product.price.normalize().to_string(),
+                    product.currency.clone(),
+                    escape_formula(&product.category),
+                    product.created_at.to_rfc3339(),
+                    product.updated_at.to_rfc3339(),
+                ])?;
+            }
+            writer.into_inner().map_err(|err| err.into_error())
+        }
+        Format::Ndjson => {
+            let mut buf = Vec::new();
+            for product in products {
+                serde_json::to_writer(&mut buf, product)?;
+                buf.push(b'\n');
+            }
+            Ok(buf)
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryExportParams {
+    /// csv (기본) 또는 ndjson
+    format: Option<String>,
+}
+
+// category export 한 행 (parent_id 대신 import에서 사용하는 상위 category 이름)
+#[derive(Serialize)]
+struct CategoryExportRow<'a> {
+    id: i32,
+    name: &'a str,
+    parent: Option<&'a str>,
+    created_at: String,
+    updated_at: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/categories/export",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(CategoryExportParams),
+    responses(
+        (status = 200, description = "All categories that are not deleted, parents before their subcategories, as CSV or NDJSON (CSV cells starting with =, +, -, @, TAB or CR are prefixed with ')",
+            content(("text/csv" = String), ("application/x-ndjson" = String))
+        ),
+        (status = 400, description = "Unsupported format", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Categories"
+)]
+pub async fn export_categories_handler(
+    State(conn): State<DatabaseConnection>,
+    Query(params): Query<CategoryExportParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = Format::parse(params.format.as_deref().unwrap_or("csv"))?;
+
+    // 상위 category가 먼저 와야 다시 import할 수 있으므로 product와 달리 한 번에 조회해서 정렬한다.
+    // -- category 수는 product에 비해 적다.
+    let categories = category::Entity::find()
+        .filter(category::Column::DeletedAt.is_null())
+        .order_by_asc(category::Column::Id)
+        .all(&conn)
+        .await?;
+    let names: HashMap<i32, String> = categories.iter().map(|category| (category.id, category.name.clone())).collect();
+    let rows: Vec<CategoryExportRow> = parents_first(&categories)
+        .into_iter()
+        .map(|category| CategoryExportRow {
+            id: category.id,
+            name: &category.name,
+            parent: category.parent_id.and_then(|id| names.get(&id)).map(String::as_str),
+            created_at: category.created_at.to_rfc3339(),
+            updated_at: category.updated_at.to_rfc3339(),
+        })
+        .collect();
+
+    let (content_type, file_name) = match format {
+        Format::Csv => ("text/csv; charset=utf-8", "categories.csv"),
+        Format::Ndjson => ("application/x-ndjson", "categories.ndjson"),
+    };
+    let headers = [
+        (header::CONTENT_TYPE, content_type.to_string()),
+        (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+    ];
+    let body = encode_categories(format, &rows).map_err(|err| {
+        error!("Category export failed: {:?}", err);
+        AppError::Internal("Category export failed".into())
+    })?;
+
+    Ok((headers, body))
+}
+
+// 상위 category부터 너비 우선으로 정렬 (같은 level에서는 id 순)
+// -- 상위 category가 삭제되었거나 없는 category는 최상위로 취급한다.
+fn parents_first(categories: &[category::Model]) -> Vec<&category::Model> {
+    let ids: HashSet<i32> = categories.iter().map(|category| category.id).collect();
+    let mut children: HashMap<Option<i32>, Vec<&category::Model>> = HashMap::new();
+    for category in categories {
+        let parent_id = category.parent_id.filter(|id| ids.contains(id));
+        children.entry(parent_id).or_default().push(category);
+    }
+
+    let mut sorted = Vec::with_capacity(categories.len());
+    let mut queue: VecDeque<&category::Model> = children.remove(&None).unwrap_or_default().into();
+    while let Some(category) = queue.pop_front() {
+        queue.extend(children.remove(&Some(category.id)).unwrap_or_default());
+        sorted.push(category);
+    }
+    sorted
+}
+
+fn encode_categories(format: Format, rows: &[CategoryExportRow]) -> Result<Vec<u8>, io::Error> {
+    match format {
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(CATEGORY_CSV_COLUMNS)?;
+            for row in rows {
+                writer.write_record([
+                    row.id.to_string(),
+                    escape_formula(row.name),
+                    row.parent.map(escape_formula).unwrap_or_default(),
+                    row.created_at.clone(),
+                    row.updated_at.clone(),
+                ])?;
+            }
+            writer.into_inner().map_err(|err| err.into_error())
+        }
+        Format::Ndjson => {
+            let mut buf = Vec::new();
+            for row in rows {
+                serde_json::to_writer(&mut buf, row)?;
+                buf.push(b'\n');
+            }
+            Ok(buf)
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderName, StatusCode},
+    Json,
+};
+use chrono::Utc;
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait,
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, QueryFilter,
+    QuerySelect, TransactionTrait,
+};
+use serde::Deserialize;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::{info, warn};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::{
+    api::inventory::{self, AdjustmentReason},
+    config::Config,
+    entities::reservation::{ActiveModel, Column, Entity, Model},
+    utils::app_error::{AppError, FieldError},
+    utils::auth_user::AuthUser,
+    utils::validated_json::ValidatedJson,
+};
+
+// reservation.status
+// -- pending에서만 다른 상태로 바뀔 수 있다. (confirmed, cancelled, expired는 최종 상태)
+pub const STATUS_PENDING: &str = "pending";
+pub const STATUS_CONFIRMED: &str = "confirmed";
+pub const STATUS_CANCELLED: &str = "cancelled";
+pub const STATUS_EXPIRED: &str = "expired";
+
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct ReserveModel {
+    #[schema(example = 1)]
+    product_id: i32,
+    #[schema(example = 2, minimum = 1, maximum = 10000)]
+    #[validate(range(min = 1, max = 10000))]
+    quantity: i32,
+    // 예약 유효 시간 (초, 생략하면 서버 설정값)
+    #[schema(example = 900, minimum = 1)]
+    #[validate(range(min = 1))]
+    ttl_secs: Option<i64>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/reservations",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = inline(ReserveModel),
+    responses(
+        (status = 201, description = "Stock reserved until expires_at", body = Model,
+            headers(
+                ("location" = String, description = "URL of the created reservation")
+            )
+        ),
+        (status = 404, description = "Product not found", body = ErrorResponse),
+        (status = 409, description = "Not enough available stock", body = ErrorResponse),
+        (status = 422, description = "Invalid quantity or ttl_secs", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Reservations"
+)]
+pub async fn create_reservation_handler(
+    State(conn): State<DatabaseConnection>,
+    State(config): State<Arc<Config>>,
+    auth_user: AuthUser,
+    ValidatedJson(body): ValidatedJson<ReserveModel>,
+) -> Result<(StatusCode, [(HeaderName, String); 1], Json<Model>), AppError> {
+    let ttl_secs = body.ttl_secs.unwrap_or(config.inventory.reservation_ttl_secs);
+    if ttl_secs > config.inventory.max_reservation_ttl_secs {
+        return Err(AppError::Validation(
+            "Invalid request body".into(),
+            vec![FieldError::new(
+                "ttl_secs",
+                format!("must be at most {}", config.inventory.max_reservation_ttl_secs),
+            )],
+        ));
+    }
+
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+
+    inventory::ensure_product(&txn, body.product_id).await?;
+    let (stock, _) = inventory::lock_inventory(&txn, body.product_id, now).await?;
+
+    let available = stock.quantity - stock.reserved;
+    if body.quantity > available {
+        return Err(AppError::Conflict(format!("Insufficient stock: {} available", available)));
+    }
+
+    let reserved = stock.reserved + body.quantity;
+    inventory::update_inventory(&txn, stock, None, Some(reserved), now).await?;
+
+    let created = ActiveModel {
+        product_id: ActiveValue::Set(body.product_id),
+        user_id: ActiveValue::Set(auth_user.id),
+        quantity: ActiveValue::Set(body.quantity),
+        status: ActiveValue::Set(STATUS_PENDING.to_string()),
+        expires_at: ActiveValue::Set(now + chrono::Duration::seconds(ttl_secs)),
+        created_at: ActiveValue::Set(now),
+        updated_at: ActiveValue::Set(now),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+    txn.commit().await?;
+
+    let location = format!("/api/v1/reservations/{}", created.id);
+    Ok((StatusCode::CREATED, [(header::LOCATION, location)], Json(created)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/reservations/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i64, Path, description = "Reservation ID")
+    ),
+    responses(
+        (status = 200, description = "Reservation found", body = Model),
+        (status = 403, description = "Reservation belongs to another user", body = ErrorResponse),
+        (status = 404, description = "Reservation not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Reservations"
+)]
+pub async fn get_reservation_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<Model>, AppError> {
+    let reservation = find_reservation(&conn, &auth_user, id).await?;
+    Ok(Json(reservation))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/reservations/{id}/confirm",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i64, Path, description = "Reservation ID")
+    ),
+    responses(
+        (status = 200, description = "Reservation confirmed and stock deducted", body = Model),
+        (status = 403, description = "Reservation belongs to another user", body = ErrorResponse),
+        (status = 404, description = "Reservation not found", body = ErrorResponse),
+        (status = 409, description = "Reservation is no longer pending (confirmed, cancelled or expired)", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Reservations"
+)]
+pub async fn confirm_reservation_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<Json<Model>, AppError> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+
+    let (stock, reservation) = lock_pending(&txn, &auth_user, id, now).await?;
+    let reservation = match reservation {
+        Ok(reservation) => reservation,
+        Err(err) => {
+            // 만료 처리된 예약은 반영한 뒤 거부한다.
+            txn.commit().await?;
+            return Err(err);
+        }
+    };
+
+    // 예약한 수량은 이미 reserved에 포함되어 있으므로 quantity와 reserved를 함께 줄인다.
+    let quantity = stock.quantity - reservation.quantity;
+    let reserved = stock.reserved - reservation.quantity;
+    let stock = inventory::update_inventory(&txn, stock, Some(quantity), Some(reserved), now).await?;
+    inventory::record_adjustment(
+        &txn,
+        Some(&auth_user),
+        &stock,
+        -reservation.quantity,
+        AdjustmentReason::Sale,
+        Some(format!("reservation #{}", reservation.id)),
+    )
+    .await?;
+
+    let confirmed = set_status(&txn, reservation, STATUS_CONFIRMED, now).await?;
+    txn.commit().await?;
+
+    Ok(Json(confirmed))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/reservations/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i64, Path, description = "Reservation ID")
+    ),
+    responses(
+        (status = 204, description = "Reservation cancelled and stock released"),
+        (status = 403, description = "Reservation belongs to another user", body = ErrorResponse),
+        (status = 404, description = "Reservation not found", body = ErrorResponse),
+        (status = 409, description = "Reservation is no longer pending (confirmed, cancelled or expired)", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Reservations"
+)]
+pub async fn cancel_reservation_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let now = Utc::now().fixed_offset();
+    let txn = conn.begin().await?;
+
+    let (stock, reservation) = lock_pending(&txn, &auth_user, id, now).await?;
+    let reservation = match reservation {
+        Ok(reservation) => reservation,
+        Err(err) => {
+            txn.commit().await?;
+            return Err(err);
+        }
+    };
+
+    let reserved = stock.reserved - reservation.quantity;
+    inventory::update_inventory(&txn, stock, None, Some(reserved), now).await?;
+    set_status(&txn, reservation, STATUS_CANCELLED, now).await?;
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 본인 또는 관리자만 예약을 조회/변경할 수 있다.
+async fn find_reservation<C: ConnectionTrait>(
+    conn: &C,
+    auth_user: &AuthUser,
+    id: i64,
+) -> Result<Model, AppError> {
+    let reservation = Entity::find_by_id(id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Reservation not found".into()))?;
+    auth_user.ensure_owner_or_admin(reservation.user_id)?;
+    Ok(reservation)
+}
+
+// 예약한 product의 재고를 lock한 뒤 예약을 다시 읽는다. (lock 순서: inventory -> reservation)
+// -- 만료 시각이 지난 예약은 lock_inventory에서 expired로 바뀌므로 pending이 아니면 Err(409)를 돌려준다.
+//    이 경우에도 만료 처리는 commit해야 하므로 바깥 Result가 아닌 안쪽 Result로 반환한다.
+async fn lock_pending(
+    txn: &DatabaseTransaction,
+    auth_user: &AuthUser,
+    id: i64,
+    now: DateTimeWithTimeZone,
+) -> Result<(crate::entities::inventory::Model, Result<Model, AppError>), AppError> {
+    let reservation = find_reservation(txn, auth_user, id).await?;
+    let (stock, _) = inventory::lock_inventory(txn, reservation.product_id, now).await?;
+
+    let reservation = Entity::find_by_id(id)
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Reservation not found".into()))?;
+    if reservation.status != STATUS_PENDING {
+        let err = AppError::Conflict(format!("Reservation is {}", reservation.status));
+        return Ok((stock, Err(err)));
+    }
+    Ok((stock, Ok(reservation)))
+}
+
+async fn set_status<C: ConnectionTrait>(
+    conn: &C,
+    reservation: Model,
+    status: &str,
+    now: DateTimeWithTimeZone,
+) -> Result<Model, DbErr> {
+    let mut active: ActiveModel = reservation.into();
+    active.status = ActiveValue::Set(status.to_string());
+    active.updated_at = ActiveValue::Set(now);
+    active.update(conn).await
+}
+
+// product의 만료된 pending 예약을 expired로 바꾸고 (예약 수, 예약 수량 합계)를 반환한다.
+// -- 호출하는 쪽에서 inventory row를 lock한 상태여야 하며 reserved는 호출하는 쪽에서 줄인다.
+pub async fn release_expired<C: ConnectionTrait>(
+    conn: &C,
+    product_id: i32,
+    now: DateTimeWithTimeZone,
+) -> Result<(u64, i32), DbErr> {
+    let expired = Entity::find()
+        .filter(Column::ProductId.eq(product_id))
+        .filter(Column::Status.eq(STATUS_PENDING))
+        .filter(Column::ExpiresAt.lte(now))
+        .lock_exclusive()
+        .all(conn)
+        .await?;
+    if expired.is_empty() {
+        return Ok((0, 0));
+    }
+
+    let released = expired.iter().map(|reservation| reservation.quantity).sum();
+    let result = Entity::update_many()
+        .col_expr(Column::Status, Expr::value(STATUS_EXPIRED))
+        .col_expr(Column::UpdatedAt, Expr::value(now))
+        .filter(Column::Id.is_in(expired.iter().map(|reservation| reservation.id)))
+        .exec(conn)
+        .await?;
+    Ok((result.rows_affected, released))
+}
+
+// 만료 시각이 지난 pending 예약을 모두 expired로 바꾸고 예약 수량을 재고로 돌려놓는다.
+// -- product별로 transaction을 나눠서 한 product의 lock이 다른 product의 예약을 막지 않도록 한다.
+pub async fn expire_reservations(conn: &DatabaseConnection) -> Result<u64, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let product_ids: Vec<i32> = Entity::find()
+        .select_only()
+        .column(Column::ProductId)
+        .distinct()
+        .filter(Column::Status.eq(STATUS_PENDING))
+        .filter(Column::ExpiresAt.lte(now))
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    let mut expired = 0;
+    for product_id in product_ids {
+        let txn = conn.begin().await?;
+        let (_, count) = inventory::lock_inventory(&txn, product_id, now).await?;
+        txn.commit().await?;
+        expired += count;
+    }
+    Ok(expired)
+}
+
+// 만료된 예약을 주기적으로 정리하는 background task
+// -- 예약/확정/취소 요청도 해당 product의 만료된 예약을 먼저 정리하므로,
+//    이 task는 요청이 없는 product의 재고를 돌려놓는 역할을 한다.
+pub fn spawn_expiry_task(conn: DatabaseConnection, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match expire_reservations(&conn).await {
+                Ok(0) => {}
+                Ok(count) => info!("Expired {} stock reservations", count),
+                Err(err) => warn!("Failed to expire stock reservations: {}", err),
+            }
+        }
+    })
+}
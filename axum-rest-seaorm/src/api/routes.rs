@@ -0,0 +1,155 @@
+use axum::{
+    extract::{DefaultBodyLimit, Request},
+    handler::Handler,
+    http::{header, HeaderValue},
+    middleware::{self, Next},
+    response::Response,
+    routing::{get, post},
+    Router,
+};
+use super::{audit, auth, category, inventory, orders, product, product_image, product_io, reservation, users};
+use tower_http::timeout::TimeoutLayer;
+use crate::state::AppState;
+use crate::utils::jwt;
+
+// ----------------------------------
+// /api/v1 RESTful routes
+// -- 리소스 경로에 path id를 사용하고 POST는 201 + Location, DELETE는 204를 응답한다.
+// ----------------------------------
+pub fn v1_router(state: AppState) -> Router<AppState> {
+    let max_upload_bytes = state.config.server.max_upload_bytes;
+    let timeout = state.config.server.request_timeout();
+    let import_timeout = state.config.server.import_timeout();
+    let authenticate = middleware::from_fn_with_state(state, jwt::authenticate);
+
+    // 큰 파일을 받는 route는 request_timeout 대신 import_timeout을 적용
+    let uploads = Router::new()
+        .route("/products/import",
+            post(product_io::import_products_handler
+                .layer(middleware::from_fn(jwt::require_admin))
+                .layer(DefaultBodyLimit::max(max_upload_bytes)))
+        )
+        .route("/products/:id/images",
+            post(product_image::upload_image_handler
+                .layer(middleware::from_fn(jwt::require_admin))
+                .layer(DefaultBodyLimit::max(max_upload_bytes)))
+        )
+        .route("/categories/import",
+            post(product_io::import_categories_handler
+                .layer(middleware::from_fn(jwt::require_admin))
+                .layer(DefaultBodyLimit::max(max_upload_bytes)))
+        )
+        .route_layer(authenticate.clone())
+        .layer(TimeoutLayer::new(import_timeout));
+
+    let protected = Router::new()
+        .route("/users", get(users::list_users_handler))
+        .route("/users/:id", get(users::get_user_by_id_handler)
+            .put(users::replace_user_handler)
+            .patch(users::patch_user_handler)
+            .delete(users::delete_user_by_id_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/products", get(product::list_products_handler)
+            .post(product::create_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .delete(product::bulk_delete_products_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/products/search", get(product::search_products_handler))
+        .route("/products/export", get(product_io::export_products_handler))
+        .route("/products/:id", get(product::get_product_by_id_handler)
+            .put(product::replace_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .patch(product::patch_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .delete(product::delete_product_by_id_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/products/:id/price-history", get(product::price_history_handler))
+        .route("/products/:id/restore",
+            post(product::restore_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/products/:id/images", get(product_image::list_images_handler))
+        .route("/products/:id/images/:image_id", get(product_image::get_image_handler)
+            .delete(product_image::delete_image_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/products/:id/images/:image_id/content", get(product_image::image_content_handler))
+        .route("/products/:id/images/:image_id/thumbnail", get(product_image::image_thumbnail_handler))
+        .route("/products/:id/inventory", get(inventory::get_inventory_handler))
+        .route("/products/:id/inventory/adjustments",
+            get(inventory::list_adjustments_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .post(inventory::adjust_inventory_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/reservations", post(reservation::create_reservation_handler))
+        .route("/reservations/:id", get(reservation::get_reservation_handler)
+            .delete(reservation::cancel_reservation_handler)
+        )
+        .route("/reservations/:id/confirm", post(reservation::confirm_reservation_handler))
+        .route("/orders", get(orders::list_orders_handler).post(orders::checkout_handler))
+        .route("/orders/:id", get(orders::get_order_handler).patch(orders::update_order_status_handler))
+        .route("/categories", get(category::list_categories_handler)
+            .post(category::create_category_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/categories/tree", get(category::category_tree_handler))
+        .route("/categories/export", get(product_io::export_categories_handler))
+        .route("/categories/:name", get(category::get_category_by_name_handler)
+            .patch(category::rename_category_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .delete(category::delete_category_by_name_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/categories/:name/tree", get(category::category_subtree_handler))
+        .route("/categories/:name/move",
+            post(category::move_category_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/audit",
+            get(audit::list_audit_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route_layer(authenticate);
+
+    Router::new()
+        .merge(protected)
+        .route("/auth/login", post(auth::login_handler))
+        .route("/auth/refresh", post(auth::refresh_handler))
+        .route("/auth/logout", post(auth::logout_handler))
+        .route("/auth/signup", post(users::signup_handler))
+        .layer(TimeoutLayer::new(timeout))
+        .merge(uploads)
+}
+
+// ----------------------------------
+// 기존(unversioned) routes
+// -- 하위 호환을 위해 유지하며 모든 응답에 Deprecation 헤더를 추가한다.
+// ----------------------------------
+#[allow(deprecated)]
+pub fn legacy_router(state: AppState) -> Router<AppState> {
+    let protected = Router::new()
+        .route("/user", get(users::get_user_handler))
+        .route("/users", get(users::get_users_handler)
+            .put(users::put_user_handler)
+            .delete(users::delete_user_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/categories", get(category::get_category_handler)
+            .post(category::post_category_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .delete(category::delete_category_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route("/product", get(product::get_product_handler)
+            .post(product::post_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .put(product::put_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+            .delete(product::delete_product_handler.layer(middleware::from_fn(jwt::require_admin)))
+        )
+        .route_layer(middleware::from_fn_with_state(state, jwt::authenticate));
+
+    Router::new()
+        .merge(protected)
+        .route("/auth/login", post(auth::login_handler))
+        .route("/auth/refresh", post(auth::refresh_handler))
+        .route("/auth/logout", post(auth::logout_handler))
+        .route("/auth/signup", post(users::post_user_handler))
+        .layer(middleware::from_fn(deprecation))
+}
+
+async fn deprecation(request: Request, next: Next) -> Response {
+    let mut response = next.run(request).await;
+
+    let headers = response.headers_mut();
+    headers.insert("deprecation", HeaderValue::from_static("true"));
+    headers.append(
+        header::LINK,
+        HeaderValue::from_static("</api/v1>; rel=\"successor-version\""),
+    );
+    response
+}
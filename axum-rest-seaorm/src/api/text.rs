@@ -1,5 +1,4 @@
 use tokio::fs;
-use utoipa::path;
 
 #[utoipa::path(
     get,
@@ -1,15 +1,29 @@
 use std::collections::HashMap;
 
-use axum::{extract::{Query, State}, http::StatusCode, Json};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderName, StatusCode},
+    Json,
+};
 use sea_orm::{
     ColumnTrait, Condition, DatabaseConnection, EntityTrait, 
-    QueryFilter, QueryOrder, Order,
+    QueryFilter, QuerySelect, TransactionTrait,
     ActiveModelTrait, ActiveValue, ModelTrait
 };
 use crate::entities::users::{ActiveModel, Column, Entity, Model};
-use crate::utils::app_error::AppError;
-use crate::utils::hash::hash_password;
+use crate::utils::app_error::{AppError, FieldError};
+use crate::utils::audit::AuditEntry;
+use crate::utils::auth_user::AuthUser;
+use crate::utils::hash::PasswordHasher;
+use crate::utils::jwt::ROLE_USER;
+use crate::utils::pagination::{ListQuery, ListSpec, Page, PaginationParams};
+use crate::utils::refresh_token;
+use crate::utils::validated_json::{require_fields, ValidatedJson};
 use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+// audit_log.entity 값
+pub const AUDIT_ENTITY: &str = "users";
 
 // Wrapper functions for OpenAPI documentation
 #[utoipa::path(
@@ -30,6 +44,7 @@ use utoipa::ToSchema;
     ),
     tag = "Users"
 )]
+#[deprecated(note = "use GET /api/v1/users/{id}")]
 pub async fn get_user_handler(
     Query(params): Query<QueryParams>,
     State(conn): State<DatabaseConnection>,
@@ -47,10 +62,7 @@ pub async fn get_user(
         match id.parse::<i32>() {
             Ok(parsed_id) => condition = condition.add(Column::Id.eq(parsed_id)),
             Err(_) => {
-                return Err(AppError::new(
-                    StatusCode::BAD_REQUEST,
-                    "ID must be an integer",
-                ));
+                return Err(AppError::BadRequest("ID must be an integer".into()));
             }
         }
     }
@@ -75,14 +87,8 @@ pub async fn get_user(
         .await
     {
         Ok(Some(user)) => Ok(Json(user)),
-        Ok(None) => Err(AppError::new(
-            StatusCode::NOT_FOUND, 
-            "User not found"
-        )),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR, 
-            "Database error"
-        )),
+        Ok(None) => Err(AppError::NotFound("User not found".into())),
+        Err(err) => Err(err.into()),
     }
 }
 
@@ -94,36 +100,51 @@ pub async fn get_user(
     ),
     params(
         ("id" = Option<String>, Query, description = "User ID"),
-        ("username" = Option<String>, Query, description = "Username to search")
+        ("username" = Option<String>, Query, description = "Username to search"),
+        PaginationParams,
+        ("id_gte" = Option<i32>, Query, description = "Minimum user ID"),
+        ("id_lte" = Option<i32>, Query, description = "Maximum user ID")
     ),
     responses(
-        (status = 200, description = "List of users", body = Vec<Model>),
+        (status = 200, description = "Page of users (sortable by id, username)", body = UserPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching users")
+            )
+        ),
         (status = 400, description = "Invalid parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Users"
 )]
+#[deprecated(note = "use GET /api/v1/users")]
 pub async fn get_users_handler(
     State(conn): State<DatabaseConnection>,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<Vec<Model>>, AppError> {
-    get_users(State(conn), Query(params)).await
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    get_users(State(conn), Query(params), list).await
 }
 
+const LIST_SPEC: ListSpec<Column> = ListSpec {
+    sortable: &[Column::Id, Column::Username],
+    filterable: &[Column::Id],
+    default_sort: &[Column::Username],
+    cursor: Column::Id,
+};
+
 pub async fn get_users(
     State(conn): State<DatabaseConnection>,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<Vec<Model>>, AppError> {
-    let mut condition = Condition::all();
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    let mut condition = Condition::all().add(Column::DeletedAt.is_null());
 
     if let Some(id) = params.get("id") {
         match id.parse::<i32>() {
             Ok(parsed_id) => condition = condition.add(Column::Id.eq(parsed_id)),
             Err(_) => {
-                return Err(AppError::new(
-                    StatusCode::BAD_REQUEST, 
-                    "ID must be an integer"
-                ));
+                return Err(AppError::BadRequest("ID must be an integer".into()));
             }
         }
     }
@@ -135,18 +156,7 @@ pub async fn get_users(
     // println!("id: {:?}", params.get("id"));
     // println!("username: {:?}", params.get("username"));
     
-    match Entity::find()
-        .filter(condition)
-        .order_by(Column::Username, Order::Asc)
-        .all(&conn)
-        .await
-    {
-        Ok(users) => Ok(Json(users)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR, 
-            "Database error"
-        )),
-    }
+    list.fetch(&conn, Entity::find().filter(condition), &LIST_SPEC).await
 }
 
 #[derive(serde::Deserialize, ToSchema)]
@@ -163,16 +173,47 @@ pub struct DeleteParams {
     pub id: String,
 }
 
-#[derive(serde::Deserialize, ToSchema)]
+#[derive(serde::Deserialize, ToSchema, Validate)]
 pub struct UpsertModel {
-    #[schema(example = 1)]
+    #[schema(example = 1, minimum = 1)]
+    #[validate(range(min = 1))]
     pub id: Option<i32>,
-    #[schema(example = "john_doe")]
+    /// 영문자, 숫자, `_`, `.`, `-` 만 사용 가능
+    #[schema(example = "john_doe", min_length = 3, max_length = 32, pattern = r"^[A-Za-z0-9_.\-]+$")]
+    #[validate(length(min = 3, max = 32), custom(function = "validate_username"))]
     pub username: Option<String>,
-    #[schema(example = "secure_password")]
+    /// 8자 이상 72 bytes 이하 (UTF-8 기준), 영문자와 숫자를 각각 하나 이상 포함
+    #[schema(example = "secure_password1", min_length = 8, max_length = 72, format = Password)]
+    #[validate(length(min = 8), custom(function = "validate_password"))]
     pub password: Option<String>,
 }
 
+fn validate_username(username: &str) -> Result<(), ValidationError> {
+    if !username.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')) {
+        return Err(ValidationError::new("charset")
+            .with_message("may only contain letters, digits, '_', '.' and '-'".into()));
+    }
+    Ok(())
+}
+
+// bcrypt는 72 bytes까지만 사용하므로 길이 상한은 글자 수가 아닌 byte 수로 검사한다.
+// -- 한글 등 multi-byte 문자는 72자보다 적어도 잘려서 hash될 수 있다.
+const MAX_PASSWORD_BYTES: usize = 72;
+
+fn validate_password(password: &str) -> Result<(), ValidationError> {
+    if password.len() > MAX_PASSWORD_BYTES {
+        return Err(ValidationError::new("length")
+            .with_message(format!("length must be at most {} bytes", MAX_PASSWORD_BYTES).into()));
+    }
+    let has_letter = password.chars().any(|c| c.is_alphabetic());
+    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    if !has_letter || !has_digit {
+        return Err(ValidationError::new("password_strength")
+            .with_message("must contain at least one letter and one digit".into()));
+    }
+    Ok(())
+}
+
 #[utoipa::path(
     post,
     path = "/auth/signup",
@@ -182,44 +223,50 @@ pub struct UpsertModel {
     request_body = inline(UpsertModel),
     responses(
         (status = 200, description = "User created", body = Model),
-        (status = 400, description = "Invalid input", body = ErrorResponse),
+        (status = 409, description = "Username already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid input", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Auth"
 )]
+#[deprecated(note = "use POST /api/v1/auth/signup")]
 pub async fn post_user_handler(
     State(conn): State<DatabaseConnection>,
-    Json(user): Json<UpsertModel>,
+    State(hasher): State<PasswordHasher>,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    create_user(State(conn), Json(user)).await
+    create_user(State(conn), State(hasher), ValidatedJson(user)).await
 }
 
 pub async fn create_user(
     State(conn): State<DatabaseConnection>,
-    Json(user): Json<UpsertModel>,
+    State(hasher): State<PasswordHasher>,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    if user.username.is_none() || user.password.is_none() {
-        return Err(AppError::new(
-            StatusCode::BAD_REQUEST,
-            "Username or password not provided"
-        ));
-    }
+    require_fields(
+        "Username or password not provided",
+        &[("username", user.username.is_some()), ("password", user.password.is_some())],
+    )?;
 
-    let hashed_password = hash_password(&user.password.unwrap())?;
+    let hashed_password = hasher.hash(user.password.unwrap_or_default()).await?;
 
     let new_user = ActiveModel {
         id: ActiveValue::NotSet,
-        username: ActiveValue::Set(user.username.unwrap()),
+        username: ActiveValue::Set(user.username.unwrap_or_default()),
         password: ActiveValue::Set(hashed_password),
+        role: ActiveValue::Set(ROLE_USER.to_string()),
+        ..Default::default()
     };
 
-    match new_user.insert(&conn).await {
-        Ok(result) => Ok(Json(result)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
-    }
+    // 회원 가입은 인증 전 요청이므로 actor 없이 기록
+    let txn = conn.begin().await?;
+    let result = new_user.insert(&txn).await?;
+    AuditEntry::created(AUDIT_ENTITY, result.id, &result)
+        .write(&txn, None)
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(result))
 }
 
 #[utoipa::path(
@@ -231,63 +278,90 @@ pub async fn create_user(
     request_body = inline(UpsertModel),
     responses(
         (status = 200, description = "User updated", body = Model),
-        (status = 400, description = "Invalid input", body = ErrorResponse),
+        (status = 409, description = "Username already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid input", body = ErrorResponse),
+        (status = 403, description = "Not the account owner", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Users"
 )]
+#[deprecated(note = "use PUT/PATCH /api/v1/users/{id}")]
 pub async fn put_user_handler(
     State(conn): State<DatabaseConnection>,
-    Json(user): Json<UpsertModel>,
+    State(hasher): State<PasswordHasher>,
+    auth_user: AuthUser,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
-    put_user(State(conn), Json(user)).await
+    put_user(State(conn), State(hasher), auth_user, ValidatedJson(user)).await
 }
 
 pub async fn put_user(
     State(conn): State<DatabaseConnection>,
-    Json(user): Json<UpsertModel>,
+    State(hasher): State<PasswordHasher>,
+    auth_user: AuthUser,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
 ) -> Result<Json<Model>, AppError> {
     let id = match user.id {
         Some(id) => id,
         None => {
-            return Err(AppError::new(
-                StatusCode::BAD_REQUEST,
-                "ID not provided"
+            return Err(AppError::Validation(
+                "ID not provided".into(),
+                vec![FieldError::new("id", "is required")],
             ));
         }
     };
 
-    let found_user = match Entity::find_by_id(id).one(&conn).await {
-        Ok(user) => user.ok_or(AppError::new(
-            StatusCode::NOT_FOUND,
-            "User not found"
-        ))?,
-        Err(_) => return Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+    update_user(&conn, &hasher, &auth_user, id, user).await.map(Json)
+}
+
+// 전달된 필드만 변경 (username, password)
+async fn update_user(
+    conn: &DatabaseConnection,
+    hasher: &PasswordHasher,
+    auth_user: &AuthUser,
+    id: i32,
+    user: UpsertModel,
+) -> Result<Model, AppError> {
+    // 본인 계정만 수정 가능 (admin은 모든 계정 수정 가능)
+    auth_user.ensure_owner_or_admin(id)?;
+
+    // bcrypt 해시는 느리므로 row lock을 잡기 전에 계산
+    let hashed_password = match user.password {
+        Some(password) => Some(hasher.hash(password).await?),
+        None => None,
     };
 
-    let mut active_user: ActiveModel = found_user.into();
+    let txn = conn.begin().await?;
+
+    let found_user = match Entity::find_by_id(id).lock_exclusive().one(&txn).await {
+        Ok(user) => user.ok_or(AppError::NotFound("User not found".into()))?,
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut active_user: ActiveModel = found_user.clone().into();
 
     active_user.username = user.username.map(ActiveValue::Set).unwrap_or(active_user.username);
-    active_user.password = user.password.map(ActiveValue::Set).unwrap_or(active_user.password);
-
-    match active_user.update(&conn).await {
-        Ok(result) => Ok(Json(result)),
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+    if let Some(hashed_password) = hashed_password {
+        active_user.password = ActiveValue::Set(hashed_password);
+        // 이전 password로 발급된 로그인 세션(refresh token)은 모두 끊는다.
+        refresh_token::revoke_all_for_user(&txn, id).await?;
     }
+
+    let result = active_user.update(&txn).await?;
+    AuditEntry::updated(AUDIT_ENTITY, id, &found_user, &result)
+        .write(&txn, Some(auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(result)
 }
 
 #[utoipa::path(
     delete,
     path = "/users",
     security(
-        ("bearer_auth" = [])
+        ("bearer_auth" = ["admin"])
     ),
     params(
         ("id" = String, Query, description = "User ID to delete")
@@ -295,19 +369,23 @@ pub async fn put_user(
     responses(
         (status = 200, description = "User deleted", body = String),
         (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     ),
     tag = "Users"
 )]
+#[deprecated(note = "use DELETE /api/v1/users/{id}")]
 pub async fn delete_user_handler(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<DeleteParams>,
 ) -> Result<Json<&'static str>, AppError> {
-    delete_user(State(conn), Query(params)).await
+    delete_user(State(conn), auth_user, Query(params)).await
 }
 
 pub async fn delete_user(
     State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
     Query(params): Query<DeleteParams>,
 ) -> Result<Json<&'static str>, AppError> {
     //-->> TimeoutLayer testing
@@ -315,30 +393,218 @@ pub async fn delete_user(
     //<<--- TimeoutLayer testing
 
     let user_id = params.id.parse::<i32>()
-        .map_err(|_| AppError::new(
-            StatusCode::BAD_REQUEST,
-            "User ID must be an integer"
-        ))?;
+        .map_err(|_| AppError::BadRequest("User ID must be an integer".into()))?;
+
+    delete_user_by_id(&conn, &auth_user, user_id).await?;
+    println!("User deleted: {}", user_id);
+    Ok(Json("User deleted"))
+}
+
+async fn delete_user_by_id(
+    conn: &DatabaseConnection,
+    auth_user: &AuthUser,
+    user_id: i32,
+) -> Result<(), AppError> {
+    let txn = conn.begin().await?;
 
     // user_id가 존재하는지 확인
     let user_to_delete = Entity::find_by_id(user_id)
-        .one(&conn)
-        .await
-        .map_err(|_| AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Database error"))?
-        .ok_or_else(|| AppError::new(StatusCode::NOT_FOUND, "User not found"))?;
-
-    match user_to_delete.delete(&conn).await {
-        Ok(_) => {
-            println!("User deleted: {}", user_id);
-            Ok(Json("User deleted"))
-        },
-        Err(_) => Err(AppError::new(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Database error"
-        )),
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+
+    user_to_delete.clone().delete(&txn).await?;
+    AuditEntry::deleted(AUDIT_ENTITY, user_id, &user_to_delete)
+        .write(&txn, Some(auth_user))
+        .await?;
+    txn.commit().await?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------
+// /api/v1 RESTful endpoints
+// ---------------------------------------------------------------
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("username" = Option<String>, Query, description = "Username to search"),
+        PaginationParams,
+        ("id_gte" = Option<i32>, Query, description = "Minimum user ID"),
+        ("id_lte" = Option<i32>, Query, description = "Maximum user ID")
+    ),
+    responses(
+        (status = 200, description = "Page of users (sortable by id, username)", body = UserPage,
+            headers(
+                ("link" = String, description = "RFC 8288 pagination links (first, prev, next, last)"),
+                ("x-total-count" = u64, description = "Total number of matching users")
+            )
+        ),
+        (status = 400, description = "Invalid parameters", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Users"
+)]
+pub async fn list_users_handler(
+    State(conn): State<DatabaseConnection>,
+    Query(params): Query<HashMap<String, String>>,
+    list: ListQuery,
+) -> Result<Page<Model>, AppError> {
+    get_users(State(conn), Query(params), list).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User found", body = Model),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Users"
+)]
+pub async fn get_user_by_id_handler(
+    State(conn): State<DatabaseConnection>,
+    Path(id): Path<i32>,
+) -> Result<Json<Model>, AppError> {
+    match Entity::find_by_id(id).one(&conn).await {
+        Ok(Some(user)) => Ok(Json(user)),
+        Ok(None) => Err(AppError::NotFound("User not found".into())),
+        Err(err) => Err(err.into()),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/signup",
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 201, description = "User created", body = Model,
+            headers(
+                ("location" = String, description = "URL of the created user")
+            )
+        ),
+        (status = 409, description = "Username already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid input", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn signup_handler(
+    State(conn): State<DatabaseConnection>,
+    State(hasher): State<PasswordHasher>,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
+) -> Result<(StatusCode, [(HeaderName, String); 1], Json<Model>), AppError> {
+    let Json(created) = create_user(State(conn), State(hasher), ValidatedJson(user)).await?;
+    let location = format!("/api/v1/users/{}", created.id);
+
+    Ok((StatusCode::CREATED, [(header::LOCATION, location)], Json(created)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 200, description = "User replaced", body = Model),
+        (status = 403, description = "Not the account owner", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Username already exists", body = ErrorResponse),
+        (status = 422, description = "username and password are required", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Users"
+)]
+pub async fn replace_user_handler(
+    State(conn): State<DatabaseConnection>,
+    State(hasher): State<PasswordHasher>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
+) -> Result<Json<Model>, AppError> {
+    // PUT은 전체 교체이므로 모든 필드가 필요
+    require_fields(
+        "Username or password not provided",
+        &[("username", user.username.is_some()), ("password", user.password.is_some())],
+    )?;
+
+    update_user(&conn, &hasher, &auth_user, id, user).await.map(Json)
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/v1/users/{id}",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    request_body = inline(UpsertModel),
+    responses(
+        (status = 200, description = "User updated", body = Model),
+        (status = 403, description = "Not the account owner", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Username already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid username or password", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Users"
+)]
+pub async fn patch_user_handler(
+    State(conn): State<DatabaseConnection>,
+    State(hasher): State<PasswordHasher>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    ValidatedJson(user): ValidatedJson<UpsertModel>,
+) -> Result<Json<Model>, AppError> {
+    update_user(&conn, &hasher, &auth_user, id, user).await.map(Json)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/{id}",
+    security(
+        ("bearer_auth" = ["admin"])
+    ),
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    responses(
+        (status = 204, description = "User deleted"),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    ),
+    tag = "Users"
+)]
+pub async fn delete_user_by_id_handler(
+    State(conn): State<DatabaseConnection>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    delete_user_by_id(&conn, &auth_user, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 // -- TimeoutLayer Testing --
 // * Preparing request to http://localhost:8000/users?id=12
 // * Current time is 2025-06-26T03:51:25.572Z
@@ -1,14 +1,55 @@
-use std::env;
+use std::time::Duration;
 
-use sea_orm::{Database, DatabaseConnection};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+use tracing::{info, warn};
 
-pub async fn init_db() -> DatabaseConnection {
-    let db_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    println!("DATABASE_URL: {}", db_url);
-    match Database::connect(db_url.as_str()).await {
-        Ok(db) => db,
-        Err(e) => {
-            panic!("Error connecting to database: {}", e);
+use crate::config::DatabaseConfig;
+
+// DB connection pool 생성
+// -- docker-compose 등에서 DB가 아직 준비되지 않았을 수 있으므로
+//    connect_attempts 횟수만큼 exponential backoff로 재시도한다.
+pub async fn init_db(config: &DatabaseConfig) -> Result<DatabaseConnection, DbErr> {
+    let mut options = ConnectOptions::new(config.url.as_str());
+    options
+        .connect_timeout(config.connect_timeout())
+        .acquire_timeout(config.acquire_timeout());
+
+    if config.is_sqlite_memory() {
+        // in-memory SQLite는 마지막 connection이 닫히면 데이터가 사라지므로
+        // connection 하나를 계속 유지한다. (idle timeout, max lifetime 없음)
+        options.max_connections(1).min_connections(1);
+    } else {
+        options
+            .max_connections(config.max_connections)
+            .min_connections(config.min_connections)
+            .idle_timeout(config.idle_timeout())
+            .max_lifetime(config.max_lifetime());
+    }
+
+    let mut backoff = Duration::from_millis(config.initial_backoff_ms);
+    let max_backoff = Duration::from_millis(config.max_backoff_ms);
+    let mut attempt = 1;
+
+    loop {
+        info!(
+            "Connecting to database {} (attempt {}/{})",
+            config.redacted_url(),
+            attempt,
+            config.connect_attempts
+        );
+
+        match Database::connect(options.clone()).await {
+            Ok(conn) => {
+                info!("Connected to database");
+                return Ok(conn);
+            }
+            Err(err) if attempt < config.connect_attempts => {
+                warn!("Failed to connect to database: {}. Retrying in {:?}", err, backoff);
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(max_backoff);
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
         }
     }
-}
\ No newline at end of file
+}
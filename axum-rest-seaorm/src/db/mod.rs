@@ -1,3 +1,4 @@
 pub mod init;
+pub mod seed;
 
-pub use init::init_db;
\ No newline at end of file
+pub use init::init_db;
@@ -0,0 +1,301 @@
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+};
+
+use sea_orm::{
+    prelude::Decimal, ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, TransactionTrait,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::api;
+use crate::entities::{category, product, users};
+use crate::utils::app_error::AppError;
+use crate::utils::audit::AuditEntry;
+use crate::utils::hash::PasswordHasher;
+use crate::utils::jwt::{ROLE_ADMIN, ROLE_USER};
+use crate::utils::money;
+
+// seed_file을 지정하지 않았을 때 사용하는 기본 데이터 (binary에 포함)
+const DEFAULT_FIXTURES: &str = include_str!("../../seed/fixtures.json");
+
+// seed 데이터 파일 형식 (JSON)
+// -- 이미 존재하는 데이터는 건너뛰므로 여러 번 실행해도 결과가 같다.
+//    category: name, product: (title, category), user: username 기준
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Fixtures {
+    pub categories: Vec<CategoryFixture>,
+    pub products: Vec<ProductFixture>,
+    pub users: Vec<UserFixture>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryFixture {
+    pub name: String,
+    // 상위 category 이름 (fixtures에서 먼저 정의되었거나 DB에 이미 있어야 함)
+    #[serde(default)]
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProductFixture {
+    pub title: String,
+    // 문자열 또는 정수 (예: "19.99", 1200)
+    #[serde(with = "money::amount")]
+    pub price: Decimal,
+    // 생략하면 KRW
+    #[serde(default)]
+    pub currency: Option<String>,
+    pub category: String,
+}
+
+impl ProductFixture {
+    fn currency(&self) -> &str {
+        self.currency.as_deref().unwrap_or(money::DEFAULT_CURRENCY)
+    }
+}
+
+// 비밀번호는 password에 직접 쓰거나, password_env로 환경 변수 이름을 지정한다.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserFixture {
+    pub username: String,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub password_env: Option<String>,
+}
+
+impl UserFixture {
+    fn password(&self) -> Option<String> {
+        self.password
+            .clone()
+            .or_else(|| self.password_env.as_ref().and_then(|key| env::var(key).ok()))
+            .filter(|password| !password.is_empty())
+    }
+}
+
+#[derive(Debug)]
+pub enum SeedError {
+    Read(PathBuf, std::io::Error),
+    Parse(String, serde_json::Error),
+    Invalid(Vec<String>),
+    Database(String),
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeedError::Read(path, err) => write!(f, "cannot read {}: {}", path.display(), err),
+            SeedError::Parse(source, err) => write!(f, "invalid seed data in {}: {}", source, err),
+            SeedError::Invalid(errors) => {
+                writeln!(f, "invalid seed data:")?;
+                for error in errors {
+                    writeln!(f, "  - {}", error)?;
+                }
+                Ok(())
+            }
+            SeedError::Database(err) => write!(f, "failed to seed database: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SeedError {}
+
+impl From<DbErr> for SeedError {
+    fn from(err: DbErr) -> Self {
+        SeedError::Database(err.to_string())
+    }
+}
+
+impl From<AppError> for SeedError {
+    fn from(err: AppError) -> Self {
+        SeedError::Database(format!("{:?}", err))
+    }
+}
+
+impl Fixtures {
+    // path가 없으면 기본 데이터를 사용한다.
+    pub fn load(path: Option<&Path>) -> Result<Self, SeedError> {
+        let fixtures = match path {
+            Some(path) => {
+                let content = std::fs::read_to_string(path)
+                    .map_err(|err| SeedError::Read(path.to_path_buf(), err))?;
+                serde_json::from_str(&content)
+                    .map_err(|err| SeedError::Parse(path.display().to_string(), err))?
+            }
+            None => serde_json::from_str(DEFAULT_FIXTURES)
+                .map_err(|err| SeedError::Parse("built-in fixtures".to_string(), err))?,
+        };
+        Ok(fixtures)
+    }
+
+    // DB에 쓰기 전에 잘못된 값을 모두 모아서 보고한다.
+    fn validate(&self) -> Result<(), SeedError> {
+        let mut errors = Vec::new();
+
+        for category in &self.categories {
+            if category.name.trim().is_empty() {
+                errors.push("categories: name must not be blank".to_string());
+            }
+        }
+        for product in &self.products {
+            if product.title.trim().is_empty() {
+                errors.push("products: title must not be blank".to_string());
+            }
+            if let Err(err) = money::non_negative_amount(&product.price) {
+                errors.push(format!("products[{:?}]: price {}", product.title, err));
+            }
+            if let Err(err) = money::currency_code(product.currency()) {
+                errors.push(format!("products[{:?}]: currency {}", product.title, err));
+            }
+            if money::check_scale("price", product.price, product.currency()).is_err() {
+                errors.push(format!(
+                    "products[{:?}]: price has too many decimal places for {}",
+                    product.title,
+                    product.currency()
+                ));
+            }
+        }
+        for user in &self.users {
+            if let Some(role) = &user.role {
+                if role != ROLE_ADMIN && role != ROLE_USER {
+                    errors.push(format!("users[{:?}]: unknown role {:?}", user.username, role));
+                }
+            }
+            if user.password().is_none() {
+                let hint = match &user.password_env {
+                    Some(key) => format!(" (set {})", key),
+                    None => String::new(),
+                };
+                errors.push(format!("users[{:?}]: password is required{}", user.username, hint));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SeedError::Invalid(errors))
+        }
+    }
+}
+
+// seed 데이터를 하나의 transaction으로 적재한다. (실패하면 전체 rollback)
+pub async fn seed(
+    conn: &DatabaseConnection,
+    hasher: &PasswordHasher,
+    fixtures: Fixtures,
+) -> Result<(), SeedError> {
+    fixtures.validate()?;
+
+    // bcrypt 해시는 느리므로 transaction을 시작하기 전에 계산
+    let mut hashed_passwords = Vec::with_capacity(fixtures.users.len());
+    for user in &fixtures.users {
+        hashed_passwords.push(hasher.hash(user.password().unwrap_or_default()).await?);
+    }
+
+    let txn = conn.begin().await?;
+
+    let mut inserted = 0;
+    for fixture in &fixtures.categories {
+        let exists = category::Entity::find()
+            .filter(category::Column::Name.eq(&fixture.name))
+            .one(&txn)
+            .await?
+            .is_some();
+        if exists {
+            continue;
+        }
+        let parent_id = match &fixture.parent {
+            Some(parent) => {
+                let parent = category::Entity::find()
+                    .filter(category::Column::Name.eq(parent))
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| {
+                        SeedError::Invalid(vec![format!(
+                            "categories[{:?}]: parent {:?} not found (define it before its children)",
+                            fixture.name, parent
+                        )])
+                    })?;
+                Some(parent.id)
+            }
+            None => None,
+        };
+        let model = category::ActiveModel {
+            name: ActiveValue::Set(fixture.name.clone()),
+            parent_id: ActiveValue::Set(parent_id),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        AuditEntry::created(api::category::AUDIT_ENTITY, model.id, &model)
+            .write(&txn, None)
+            .await?;
+        inserted += 1;
+    }
+    info!("Seeded categories: {} inserted, {} skipped", inserted, fixtures.categories.len() - inserted);
+
+    let mut inserted = 0;
+    for fixture in &fixtures.products {
+        let exists = product::Entity::find()
+            .filter(product::Column::Title.eq(&fixture.title))
+            .filter(product::Column::Category.eq(&fixture.category))
+            .one(&txn)
+            .await?
+            .is_some();
+        if exists {
+            continue;
+        }
+        let model = product::ActiveModel {
+            title: ActiveValue::Set(fixture.title.clone()),
+            price: ActiveValue::Set(fixture.price),
+            currency: ActiveValue::Set(fixture.currency().to_string()),
+            category: ActiveValue::Set(fixture.category.clone()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        AuditEntry::created(api::product::AUDIT_ENTITY, model.id, &model)
+            .write(&txn, None)
+            .await?;
+        inserted += 1;
+    }
+    info!("Seeded products: {} inserted, {} skipped", inserted, fixtures.products.len() - inserted);
+
+    let mut inserted = 0;
+    for (fixture, password) in fixtures.users.iter().zip(hashed_passwords) {
+        let exists = users::Entity::find()
+            .filter(users::Column::Username.eq(&fixture.username))
+            .one(&txn)
+            .await?
+            .is_some();
+        // 이미 있는 계정의 비밀번호/역할은 변경하지 않는다.
+        if exists {
+            continue;
+        }
+        let model = users::ActiveModel {
+            username: ActiveValue::Set(fixture.username.clone()),
+            password: ActiveValue::Set(password),
+            role: ActiveValue::Set(fixture.role.clone().unwrap_or_else(|| ROLE_USER.to_string())),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        AuditEntry::created(api::users::AUDIT_ENTITY, model.id, &model)
+            .write(&txn, None)
+            .await?;
+        inserted += 1;
+    }
+    info!("Seeded users: {} inserted, {} skipped", inserted, fixtures.users.len() - inserted);
+
+    txn.commit().await?;
+    Ok(())
+}
@@ -2,6 +2,16 @@
 
 pub mod prelude;
 
+pub mod audit_log;
 pub mod category;
+pub mod inventory;
+pub mod inventory_adjustment;
+pub mod order_items;
+pub mod orders;
+pub mod price_history;
 pub mod product;
+pub mod product_image;
+pub mod refresh_token;
+pub mod reservation;
+pub mod revoked_token;
 pub mod users;
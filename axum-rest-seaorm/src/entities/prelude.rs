@@ -1,5 +1,15 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 #![allow(unused_imports)]
+pub use super::audit_log::Entity as AuditLog;
 pub use super::category::Entity as Category;
+pub use super::inventory::Entity as Inventory;
+pub use super::inventory_adjustment::Entity as InventoryAdjustment;
+pub use super::order_items::Entity as OrderItems;
+pub use super::orders::Entity as Orders;
+pub use super::price_history::Entity as PriceHistory;
 pub use super::product::Entity as Product;
+pub use super::product_image::Entity as ProductImage;
+pub use super::refresh_token::Entity as RefreshToken;
+pub use super::reservation::Entity as Reservation;
+pub use super::revoked_token::Entity as RevokedToken;
 pub use super::users::Entity as Users;
@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -9,8 +10,16 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub title: String,
-    pub price: i32,
+    #[sea_orm(column_type = "Decimal(Some((19, 4)))")]
+    #[serde(with = "crate::utils::money::amount")]
+    #[schema(value_type = String, example = "1200")]
+    pub price: Decimal,
+    #[schema(example = "KRW")]
+    pub currency: String,
     pub category: String,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -19,10 +28,22 @@ pub enum Relation {
         belongs_to = "super::category::Entity",
         from = "Column::Category",
         to = "super::category::Column::Name",
-        on_update = "NoAction",
-        on_delete = "NoAction"
+        on_update = "Cascade",
+        on_delete = "Restrict"
     )]
     Category,
+    #[sea_orm(has_one = "super::inventory::Entity")]
+    Inventory,
+    #[sea_orm(has_many = "super::inventory_adjustment::Entity")]
+    InventoryAdjustment,
+    #[sea_orm(has_many = "super::order_items::Entity")]
+    OrderItems,
+    #[sea_orm(has_many = "super::price_history::Entity")]
+    PriceHistory,
+    #[sea_orm(has_many = "super::product_image::Entity")]
+    ProductImage,
+    #[sea_orm(has_many = "super::reservation::Entity")]
+    Reservation,
 }
 
 impl Related<super::category::Entity> for Entity {
@@ -31,4 +52,54 @@ impl Related<super::category::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+impl Related<super::inventory::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Inventory.def()
+    }
+}
+
+impl Related<super::inventory_adjustment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InventoryAdjustment.def()
+    }
+}
+
+impl Related<super::order_items::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderItems.def()
+    }
+}
+
+impl Related<super::price_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PriceHistory.def()
+    }
+}
+
+impl Related<super::product_image::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProductImage.def()
+    }
+}
+
+impl Related<super::reservation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Reservation.def()
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    // 저장할 때마다 updated_at을 갱신하고, insert 시에는 created_at도 설정한다.
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let now = chrono::Utc::now().fixed_offset();
+        if insert {
+            self.created_at = ActiveValue::Set(now);
+        }
+        self.updated_at = ActiveValue::Set(now);
+        Ok(self)
+    }
+}
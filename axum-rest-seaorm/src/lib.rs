@@ -0,0 +1,83 @@
+pub mod api;
+pub mod config;
+pub mod db;
+pub mod entities;
+pub mod state;
+pub mod storage;
+pub mod swagger;
+pub mod utils;
+
+use axum::{
+    http::{header, HeaderName, HeaderValue, Method},
+    middleware,
+    routing::get, Router
+};
+use tower_http::{
+    compression::CompressionLayer,
+    cors::{AllowOrigin, Any, CorsLayer},
+    timeout::TimeoutLayer,
+    trace::TraceLayer,
+};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use api::health;
+use api::routes;
+use api::text;
+use state::AppState;
+use swagger::ApiDoc;
+use utils::request_id;
+
+// 전체 router 구성 (main과 integration test에서 함께 사용)
+pub fn build_app(state: AppState) -> Router {
+    let timeout = state.config.server.request_timeout();
+    let cors = cors_layer(&state.config.server.cors_origins);
+
+    // OpenAPI 문서 생성
+    let openapi = ApiDoc::openapi();
+
+    // /api/v1은 route마다 timeout을 적용하므로 (import, 업로드는 import_timeout) 이 layer 뒤에 추가한다.
+    let mut app = Router::new()
+        .merge(routes::legacy_router(state.clone()))
+        .route("/text", get(text::get_text_handler))
+        .route("/healthz", get(health::healthz_handler))
+        .route("/readyz", get(health::readyz_handler))
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", openapi))
+        .layer(TimeoutLayer::new(timeout))
+        .nest("/api/v1", routes::v1_router(state.clone()))
+        .with_state(state);
+    if let Some(cors) = cors {
+        app = app.layer(cors);
+    }
+    app
+        .layer(middleware::from_fn(request_id::propagate))
+        .layer(TraceLayer::new_for_http())
+        .layer(CompressionLayer::new())
+}
+
+// 설정된 origin이 없으면 CORS 헤더를 추가하지 않는다.
+fn cors_layer(origins: &[String]) -> Option<CorsLayer> {
+    if origins.is_empty() {
+        return None;
+    }
+
+    let allow_origin = if origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::from(Any)
+    } else {
+        // Config::validate에서 형식을 검사했으므로 변환에 실패하지 않는다.
+        AllowOrigin::list(origins.iter().filter_map(|origin| HeaderValue::from_str(origin).ok()))
+    };
+
+    Some(
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+            .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, request_id::X_REQUEST_ID.clone()])
+            .expose_headers([
+                header::LINK,
+                header::LOCATION,
+                HeaderName::from_static("x-total-count"),
+                request_id::X_REQUEST_ID.clone(),
+            ]),
+    )
+}
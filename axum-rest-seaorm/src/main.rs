@@ -1,78 +1,155 @@
-mod api;
-mod db;
-mod entities;
-mod utils;
-mod swagger;
-
-use axum::{
-    middleware,
-    routing::{get, post}, Router
-};
-use sea_orm::DatabaseConnection;
-use tokio::net::TcpListener;
-use std::time::Duration;
-use tower_http::{compression::CompressionLayer, timeout::TimeoutLayer, trace::TraceLayer};
-use tracing::info;
-use tracing_subscriber::{fmt, prelude::*,EnvFilter};
-use utoipa::OpenApi;
-use utoipa_swagger_ui::SwaggerUi;
+use std::future::IntoFuture;
 
-use api::users;
-use api::category;
-use api::product;
-use api::text;
-use api::auth;
-use db::init_db;
-use utils::jwt;
-use swagger::ApiDoc;
+use tokio::{net::TcpListener, signal, sync::watch};
+use tracing::{error, info, warn};
+use tracing_subscriber::{fmt, prelude::*,EnvFilter};
 
+use axum_rest_seaorm::api::reservation;
+use axum_rest_seaorm::build_app;
+use axum_rest_seaorm::config::{Command, Config, LogConfig, LogFormat};
+use axum_rest_seaorm::db::{self, init_db, seed::Fixtures};
+use axum_rest_seaorm::state::AppState;
+use axum_rest_seaorm::utils::hash::PasswordHasher;
+use migration::{Migrator, MigratorTrait};
+use sea_orm::DatabaseConnection;
 
 #[tokio::main]
 async fn main() {
     dotenvy::dotenv().ok();
 
-    tracing_subscriber::registry()
-        .with(fmt::layer())
-        .with(EnvFilter::from_default_env())
-        .init();
+    let (config, command) = match Config::load() {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(2);
+        }
+    };
+
+    init_tracing(&config.log);
+    info!("Loaded configuration: {:?}", config);
 
-    info!("Connecting to database...");
-    let conn: DatabaseConnection = init_db().await;
+    let conn = match init_db(&config.database).await {
+        Ok(conn) => conn,
+        Err(err) => {
+            error!("Error connecting to database {}: {}", config.database.redacted_url(), err);
+            std::process::exit(1);
+        }
+    };
 
-    // OpenAPI 문서 생성
-    let openapi = ApiDoc::openapi();
+    if let Err(err) = prepare_database(&conn, &config, command).await {
+        error!("{}", err);
+        std::process::exit(1);
+    }
+    if !matches!(command, Command::Serve) {
+        let _ = conn.close().await;
+        return;
+    }
+
+    let addr = config.server.addr();
+    let shutdown_timeout = config.server.shutdown_timeout();
+    // 종료 시 pool을 닫기 위해 보관 (clone은 같은 pool을 공유한다)
+    let db = conn.clone();
+    let expiry_interval = config.inventory.expiry_interval();
+    let state = AppState::new(conn, config);
+
+    // 만료된 재고 예약 정리
+    let expiry_task = reservation::spawn_expiry_task(db.clone(), expiry_interval);
 
     info!("Starting server...");
-    let app = Router::new()
-        .route("/user", get(users::get_user_handler))
-        .route("/users", get(users::get_users_handler)
-            .put(users::put_user_handler)
-            .delete(users::delete_user_handler)
-        )
-        .route("/categories", get(category::get_category_handler)
-            .post(category::post_category_handler)
-            .delete(category::delete_category_handler)
-        )
-        .route("/product", get(product::get_product_handler)
-            .post(product::post_product_handler)
-            .put(product::put_product_handler)
-            .delete(product::delete_product_handler)
-        )
-        .route_layer(middleware::from_fn(jwt::authenticate))
-        .route("/text", get(text::get_text_handler))
-        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", openapi))
-        .route("/auth/login", post(auth::login_handler))
-        .route("/auth/signup", post(users::post_user_handler))
-        .with_state(conn)
-        .layer(TimeoutLayer::new(Duration::from_millis(3000)))
-        .layer(TraceLayer::new_for_http())
-        .layer(CompressionLayer::new());
-
-    let listener = TcpListener::bind("0.0.0.0:8000").await.unwrap();
+    let app = build_app(state);
+
+    let listener = TcpListener::bind(addr).await.unwrap();
     println!("Listening on {}", listener.local_addr().unwrap());
-    println!("Swagger UI available at http://localhost:8000/swagger-ui/");
+    println!("Swagger UI available at http://{}/swagger-ui/", listener.local_addr().unwrap());
+
+    // SIGINT/SIGTERM을 받으면 새 연결을 받지 않고 처리 중인 요청이 끝나기를 기다린다.
+    // -- shutdown_timeout이 지나도 끝나지 않은 요청은 기다리지 않고 종료한다.
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(());
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            let _ = shutdown_tx.send(());
+        })
+        .into_future();
+
+    tokio::select! {
+        result = server => {
+            if let Err(err) = result {
+                error!("Server error: {}", err);
+            }
+        }
+        _ = async {
+            let _ = shutdown_rx.changed().await;
+            tokio::time::sleep(shutdown_timeout).await;
+        } => {
+            warn!("Graceful shutdown timed out after {:?}, dropping remaining connections", shutdown_timeout);
+        }
+    }
+
+    expiry_task.abort();
+    info!("Closing database connections...");
+    if let Err(err) = db.close().await {
+        error!("Failed to close database connections: {}", err);
+    }
+    info!("Server stopped");
+}
+
+// migration / seed 적용
+// -- migrate, seed subcommand는 설정과 관계없이 실행한다. (seed는 migration 적용 후 실행)
+async fn prepare_database(
+    conn: &DatabaseConnection,
+    config: &Config,
+    command: Command,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let migrate = config.database.migrate || matches!(command, Command::Migrate | Command::Seed);
+    let seed = config.database.seed || matches!(command, Command::Seed);
+
+    if migrate {
+        info!("Applying pending migrations...");
+        Migrator::up(conn, None).await?;
+    }
+    if seed {
+        let source = match &config.database.seed_file {
+            Some(path) => path.display().to_string(),
+            None => "built-in fixtures".to_string(),
+        };
+        info!("Seeding database from {}...", source);
+        let fixtures = Fixtures::load(config.database.seed_file.as_deref())?;
+        db::seed::seed(conn, &PasswordHasher::new(&config.auth), fixtures).await?;
+    }
+    Ok(())
+}
+
+// Ctrl+C (SIGINT) 또는 SIGTERM (docker stop, k8s 등)을 기다린다.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received SIGINT, shutting down..."),
+        _ = terminate => info!("Received SIGTERM, shutting down..."),
+    }
+}
+
+fn init_tracing(log: &LogConfig) {
+    let registry = tracing_subscriber::registry().with(EnvFilter::new(&log.filter));
 
-    axum::serve(listener, app).await.unwrap();
+    match log.format {
+        LogFormat::Text => registry.with(fmt::layer()).init(),
+        LogFormat::Compact => registry.with(fmt::layer().compact()).init(),
+        LogFormat::Json => registry.with(fmt::layer().json()).init(),
+    }
 }
 
 //----------------------------------
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use axum::extract::FromRef;
+use sea_orm::DatabaseConnection;
+
+use crate::config::Config;
+use crate::storage::{self, Storage};
+use crate::utils::hash::PasswordHasher;
+use crate::utils::jwt::JwtKeys;
+
+// Router에 공유되는 애플리케이션 상태
+// -- handler는 State<DatabaseConnection>, State<JwtKeys>처럼 필요한 부분만 꺼내 쓴다. (FromRef)
+// -- 새로운 공유 서비스(cache, metrics 등)는 field로 추가하면 기존 handler 시그니처를 바꿀 필요가 없다.
+#[derive(Clone, FromRef)]
+pub struct AppState {
+    pub conn: DatabaseConnection,
+    pub config: Arc<Config>,
+    pub jwt_keys: JwtKeys,
+    pub password_hasher: PasswordHasher,
+    pub storage: Arc<dyn Storage>,
+}
+
+impl AppState {
+    pub fn new(conn: DatabaseConnection, config: Config) -> Self {
+        Self {
+            conn,
+            jwt_keys: JwtKeys::new(&config.auth),
+            password_hasher: PasswordHasher::new(&config.auth),
+            storage: storage::from_config(&config.storage),
+            config: Arc::new(config),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    io::{self, SeekFrom},
+    ops::Range,
+    path::{Component, Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use axum::body::Bytes;
+use tokio::{
+    fs,
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+};
+use tokio_util::io::ReaderStream;
+
+use super::{ByteStream, Storage};
+
+// root 디렉터리 아래에 key를 상대 경로로 사용해 저장한다.
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    // key가 root 밖을 가리키지 않도록 일반 경로 요소만 허용
+    fn path(&self, key: &str) -> io::Result<PathBuf> {
+        let relative = Path::new(key);
+        let valid = !key.is_empty()
+            && relative.components().all(|component| matches!(component, Component::Normal(_)));
+        if !valid {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("invalid storage key: {:?}", key)));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, bytes: Bytes, _content_type: &str) -> io::Result<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        // 임시 파일에 쓴 뒤 rename해서 읽는 쪽이 쓰다 만 파일을 보지 않게 한다.
+        let tmp = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+        let mut file = fs::File::create(&tmp).await?;
+        let written = async {
+            file.write_all(&bytes).await?;
+            file.sync_all().await
+        }
+        .await;
+        if let Err(err) = written {
+            let _ = fs::remove_file(&tmp).await;
+            return Err(err);
+        }
+        fs::rename(&tmp, &path).await
+    }
+
+    async fn get(&self, key: &str, range: Option<Range<u64>>) -> io::Result<ByteStream> {
+        let mut file = fs::File::open(self.path(key)?).await?;
+        match range {
+            Some(range) => {
+                file.seek(SeekFrom::Start(range.start)).await?;
+                let limited = file.take(range.end.saturating_sub(range.start));
+                Ok(Box::pin(ReaderStream::new(limited)))
+            }
+            None => Ok(Box::pin(ReaderStream::new(file))),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> io::Result<()> {
+        match fs::remove_file(self.path(key)?).await {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
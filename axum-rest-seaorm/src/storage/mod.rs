@@ -0,0 +1,34 @@
+// 업로드 파일 저장소
+// -- handler는 State<Arc<dyn Storage>>로 받아서 backend (로컬 파일 시스템, S3 호환 등)를 신경 쓰지 않는다.
+// -- key는 '/'로 구분한 상대 경로 (예: products/1/<uuid>.png)이며 서버에서만 만든다.
+pub mod local;
+
+use std::{io, ops::Range, pin::Pin, sync::Arc};
+
+use async_trait::async_trait;
+use axum::body::Bytes;
+use futures_util::Stream;
+
+use crate::config::{StorageBackend, StorageConfig};
+
+pub use local::LocalStorage;
+
+pub type ByteStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>>;
+
+#[async_trait]
+pub trait Storage: Send + Sync {
+    // 같은 key가 있으면 덮어쓴다.
+    async fn put(&self, key: &str, bytes: Bytes, content_type: &str) -> io::Result<()>;
+
+    // range가 있으면 해당 byte 범위만 읽는다. (HTTP Range 요청)
+    async fn get(&self, key: &str, range: Option<Range<u64>>) -> io::Result<ByteStream>;
+
+    // 없는 key는 무시한다.
+    async fn delete(&self, key: &str) -> io::Result<()>;
+}
+
+pub fn from_config(config: &StorageConfig) -> Arc<dyn Storage> {
+    match config.backend {
+        StorageBackend::Local => Arc::new(LocalStorage::new(&config.local_root)),
+    }
+}
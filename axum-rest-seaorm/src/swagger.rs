@@ -23,16 +23,104 @@ impl Modify for SecurityAddon {
 }
 
 // 에러 응답 스키마 (이것만 swagger.rs에 정의)
+// -- 모든 에러는 RFC 7807 application/problem+json 형식으로 응답한다. (utils::app_error::ProblemDetails)
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
+    #[serde(rename = "type")]
+    #[schema(example = "/problems/not-found")]
+    pub problem_type: String,
+    #[schema(example = "Not Found")]
+    pub title: String,
+    #[schema(example = 404)]
+    pub status: u16,
     #[schema(example = "Resource not found")]
-    pub error: String,
+    pub detail: String,
+    // 고정된 에러 코드: bad_request, validation_failed, unauthorized, forbidden, not_found, conflict, in_use, payload_too_large, unsupported_media_type, internal_error
+    #[schema(example = "not_found")]
+    pub code: String,
+    #[schema(example = "/users")]
+    pub instance: Option<String>,
+    #[schema(example = "0b5f1c8e-2f7a-4c43-9d51-0d9a1f3e6b7a")]
+    pub request_id: Option<String>,
+    pub errors: Option<Vec<FieldErrorResponse>>,
+    // code가 in_use일 때: 삭제하려는 리소스를 참조하고 있는 리소스 목록
+    pub dependents: Option<Vec<DependentResponse>>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct FieldErrorResponse {
+    #[schema(example = "title")]
+    pub field: String,
+    #[schema(example = "must not be empty")]
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DependentResponse {
+    #[schema(example = "product")]
+    pub entity: String,
+    #[schema(example = "42")]
+    pub id: String,
+    #[schema(example = "Laptop")]
+    pub name: String,
 }
 
 // API 문서 구조체
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        // /api/v1
+        crate::api::auth::login_handler,
+        crate::api::auth::refresh_handler,
+        crate::api::auth::logout_handler,
+        crate::api::users::signup_handler,
+        crate::api::users::list_users_handler,
+        crate::api::users::get_user_by_id_handler,
+        crate::api::users::replace_user_handler,
+        crate::api::users::patch_user_handler,
+        crate::api::users::delete_user_by_id_handler,
+        crate::api::product::list_products_handler,
+        crate::api::product::search_products_handler,
+        crate::api::product::create_product_handler,
+        crate::api::product::get_product_by_id_handler,
+        crate::api::product::replace_product_handler,
+        crate::api::product::patch_product_handler,
+        crate::api::product::delete_product_by_id_handler,
+        crate::api::product::restore_product_handler,
+        crate::api::product::price_history_handler,
+        crate::api::product::bulk_delete_products_handler,
+        crate::api::product_io::import_products_handler,
+        crate::api::product_io::export_products_handler,
+        crate::api::product_image::upload_image_handler,
+        crate::api::product_image::list_images_handler,
+        crate::api::product_image::get_image_handler,
+        crate::api::product_image::image_content_handler,
+        crate::api::product_image::image_thumbnail_handler,
+        crate::api::product_image::delete_image_handler,
+        crate::api::inventory::get_inventory_handler,
+        crate::api::inventory::adjust_inventory_handler,
+        crate::api::inventory::list_adjustments_handler,
+        crate::api::reservation::create_reservation_handler,
+        crate::api::reservation::get_reservation_handler,
+        crate::api::reservation::confirm_reservation_handler,
+        crate::api::reservation::cancel_reservation_handler,
+        crate::api::orders::checkout_handler,
+        crate::api::orders::list_orders_handler,
+        crate::api::orders::get_order_handler,
+        crate::api::orders::update_order_status_handler,
+        crate::api::category::list_categories_handler,
+        crate::api::category::create_category_handler,
+        crate::api::category::get_category_by_name_handler,
+        crate::api::category::rename_category_handler,
+        crate::api::category::category_tree_handler,
+        crate::api::category::category_subtree_handler,
+        crate::api::category::move_category_handler,
+        crate::api::category::delete_category_by_name_handler,
+        crate::api::product_io::import_categories_handler,
+        crate::api::product_io::export_categories_handler,
+        crate::api::audit::list_audit_handler,
+
+        // deprecated (unversioned) routes
         crate::api::users::get_user_handler,
         crate::api::users::get_users_handler,
         crate::api::users::post_user_handler,
@@ -45,8 +133,11 @@ pub struct ErrorResponse {
         crate::api::product::post_product_handler,
         crate::api::product::put_product_handler,
         crate::api::product::delete_product_handler,
-        crate::api::auth::login_handler,
         crate::api::text::get_text_handler,
+
+        // health checks
+        crate::api::health::healthz_handler,
+        crate::api::health::readyz_handler,
     ),
     components(
         schemas(
@@ -54,14 +145,49 @@ pub struct ErrorResponse {
             crate::entities::users::Model,
             crate::entities::product::Model,
             crate::entities::category::Model,
+            crate::entities::price_history::Model,
+            crate::entities::audit_log::Model,
+            crate::entities::inventory_adjustment::Model,
+            crate::entities::reservation::Model,
+            crate::entities::orders::Model,
+            crate::entities::order_items::Model,
+            crate::entities::product_image::Model,
             
             // API 요청/응답 스키마 (핸들러에 정의)
             crate::api::users::QueryParams,
             crate::api::users::DeleteParams,
             crate::api::auth::LoginRequest,
+            crate::api::auth::RefreshRequest,
+            crate::api::auth::TokenResponse,
+            crate::api::product::BulkDeleteResponse,
+            crate::api::product::SearchHit,
+            crate::api::product::SearchResponse,
+            crate::api::product_io::ImportMode,
+            crate::api::product_io::ImportUpload,
+            crate::api::product_io::ImportReport,
+            crate::api::product_io::RowError,
+            crate::api::product_image::ImageUpload,
+            crate::api::product_image::ImageResponse,
+            crate::api::inventory::InventoryResponse,
+            crate::api::inventory::AdjustmentReason,
+            crate::api::inventory::AdjustmentResponse,
+            crate::api::orders::OrderStatus,
+            crate::api::orders::OrderResponse,
+            crate::api::category::CategorySummary,
+            crate::api::category::CategoryNode,
+            crate::api::health::HealthResponse,
+            crate::utils::pagination::UserPage,
+            crate::utils::pagination::ProductPage,
+            crate::utils::pagination::CategoryPage,
+            crate::utils::pagination::CategorySummaryPage,
+            crate::utils::pagination::AuditLogPage,
+            crate::utils::pagination::InventoryAdjustmentPage,
+            crate::utils::pagination::OrderPage,
             
             // 공통 에러 응답
-            ErrorResponse
+            ErrorResponse,
+            FieldErrorResponse,
+            DependentResponse
         )
     ),
     modifiers(&SecurityAddon),
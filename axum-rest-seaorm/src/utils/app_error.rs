@@ -1,18 +1,230 @@
-use axum::{http::StatusCode, response::{IntoResponse, Response}, Json};
+use axum::{
+    extract::multipart::MultipartError,
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::{DbErr, SqlErr};
+use serde::Serialize;
+use tracing::error;
 
-pub struct AppError {
-    pub code: StatusCode,
+use super::request_id;
+
+// 필드 단위 검증 오류 (validation 에러의 errors 배열 항목)
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
     pub message: String,
 }
 
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { field: field.into(), message: message.into() }
+    }
+}
+
+// 삭제하려는 리소스를 참조하고 있는 리소스 (in_use 에러의 dependents 배열 항목)
+#[derive(Debug, Clone, Serialize)]
+pub struct Dependent {
+    pub entity: &'static str,
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub enum AppError {
+    // 요청 형식 오류 (잘못된 query 값 등) -> 400
+    BadRequest(String),
+    // 요청 본문의 필드 검증 실패 -> 422
+    Validation(String, Vec<FieldError>),
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    // unique/foreign key 제약 조건 위반 등 -> 409
+    Conflict(String),
+    // 다른 리소스가 참조하고 있어 삭제할 수 없음 -> 409 (참조하는 리소스 목록 포함)
+    InUse(String, Vec<Dependent>),
+    // 업로드 크기 제한 초과 -> 413
+    PayloadTooLarge(String),
+    // 지원하지 않는 파일 형식 (업로드한 이미지 등) -> 415
+    UnsupportedMediaType(String),
+    // 내부 오류: 상세 내용은 로그로만 남기고 클라이언트에는 일반적인 메시지만 전달
+    Internal(String),
+}
+
 impl AppError {
-    pub fn new(code: StatusCode, message: impl Into<String>) -> Self {
-        Self { code, message: message.into() }
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Validation(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) | AppError::InUse(..) => StatusCode::CONFLICT,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
     }
+
+    // 클라이언트가 분기 처리에 사용할 수 있는 고정된 에러 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Validation(..) => "validation_failed",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::InUse(..) => "in_use",
+            AppError::PayloadTooLarge(_) => "payload_too_large",
+            AppError::UnsupportedMediaType(_) => "unsupported_media_type",
+            AppError::Internal(_) => "internal_error",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::BadRequest(message)
+            | AppError::Validation(message, _)
+            | AppError::Unauthorized(message)
+            | AppError::Forbidden(message)
+            | AppError::NotFound(message)
+            | AppError::Conflict(message)
+            | AppError::InUse(message, _)
+            | AppError::PayloadTooLarge(message)
+            | AppError::UnsupportedMediaType(message)
+            | AppError::Internal(message) => message,
+        }
+    }
+}
+
+// RFC 7807 (application/problem+json) 응답 본문
+#[derive(Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    pub code: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependents: Vec<Dependent>,
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        (self.code, Json(self.message.clone())).into_response()
+        let status = self.status();
+        let code = self.code();
+        let context = request_id::current();
+
+        let detail = self.message().to_string();
+        let (errors, dependents) = match self {
+            AppError::Validation(_, errors) => (errors, Vec::new()),
+            AppError::InUse(_, dependents) => (Vec::new(), dependents),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        let problem = ProblemDetails {
+            problem_type: format!("/problems/{}", code.replace('_', "-")),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            detail,
+            code,
+            instance: context.as_ref().map(|context| context.path.clone()),
+            request_id: context.map(|context| context.request_id),
+            errors,
+            dependents,
+        };
+
+        let mut response = (status, Json(problem)).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        response
     }
-}
\ No newline at end of file
+}
+
+impl From<DbErr> for AppError {
+    fn from(err: DbErr) -> Self {
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(detail)) => {
+                error!("Unique constraint violation: {}", detail);
+                AppError::Conflict("Resource already exists".to_string())
+            }
+            Some(SqlErr::ForeignKeyConstraintViolation(detail)) => {
+                error!("Foreign key constraint violation: {}", detail);
+                AppError::Conflict("Resource is referenced by or references missing records".to_string())
+            }
+            _ => match err {
+                DbErr::RecordNotFound(message) => AppError::NotFound(message),
+                err => {
+                    error!("Database error: {:?}", err);
+                    AppError::Internal("Database error".to_string())
+                }
+            },
+        }
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for AppError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        use jsonwebtoken::errors::ErrorKind;
+
+        match err.kind() {
+            ErrorKind::ExpiredSignature => AppError::Unauthorized("Token expired".to_string()),
+            ErrorKind::InvalidToken
+            | ErrorKind::InvalidSignature
+            | ErrorKind::InvalidAlgorithm
+            | ErrorKind::ImmatureSignature
+            | ErrorKind::Base64(_)
+            | ErrorKind::Json(_)
+            | ErrorKind::Utf8(_) => {
+                error!("Error validating JWT: {:?}", err);
+                AppError::Unauthorized("Not authorized".to_string())
+            }
+            _ => {
+                error!("JWT error: {:?}", err);
+                AppError::Internal("Error processing JWT".to_string())
+            }
+        }
+    }
+}
+
+impl From<MultipartError> for AppError {
+    fn from(err: MultipartError) -> Self {
+        // DefaultBodyLimit (server.max_upload_bytes)를 넘으면 413
+        if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            AppError::PayloadTooLarge(err.body_text())
+        } else {
+            AppError::BadRequest(err.body_text())
+        }
+    }
+}
+
+// 파일 storage 오류 (storage::Storage)
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        if err.kind() == std::io::ErrorKind::NotFound {
+            error!("Stored file not found: {:?}", err);
+            AppError::NotFound("File not found".to_string())
+        } else {
+            error!("Storage error: {:?}", err);
+            AppError::Internal("Storage error".to_string())
+        }
+    }
+}
+
+impl From<bcrypt::BcryptError> for AppError {
+    fn from(err: bcrypt::BcryptError) -> Self {
+        error!("Password hashing error: {:?}", err);
+        AppError::Internal("Error processing password".to_string())
+    }
+}
@@ -0,0 +1,155 @@
+use sea_orm::{ActiveModelTrait, ActiveValue, ConnectionTrait};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use tracing::error;
+
+use super::app_error::AppError;
+use super::auth_user::AuthUser;
+use super::request_id;
+use crate::entities::audit_log::ActiveModel;
+
+// 이력에 원문을 남기지 않을 필드 (password hash 등)
+const REDACTED_FIELDS: [&str; 1] = ["password"];
+const REDACTED: &str = "[redacted]";
+
+#[derive(Clone, Copy, Debug)]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+impl Action {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::Create => "create",
+            Action::Update => "update",
+            Action::Delete => "delete",
+            Action::Restore => "restore",
+        }
+    }
+}
+
+// audit_log에 기록할 변경 내용
+// -- 변경과 같은 transaction 안에서 write를 호출해야 변경과 이력이 함께 commit/rollback 된다.
+pub struct AuditEntry {
+    action: Action,
+    entity: &'static str,
+    entity_id: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+impl AuditEntry {
+    pub fn created<M: Serialize>(entity: &'static str, entity_id: impl ToString, after: &M) -> Self {
+        Self::new(Action::Create, entity, entity_id, None, Some(after))
+    }
+
+    pub fn updated<M: Serialize>(
+        entity: &'static str,
+        entity_id: impl ToString,
+        before: &M,
+        after: &M,
+    ) -> Self {
+        Self::new(Action::Update, entity, entity_id, Some(before), Some(after))
+    }
+
+    pub fn deleted<M: Serialize>(entity: &'static str, entity_id: impl ToString, before: &M) -> Self {
+        Self::new(Action::Delete, entity, entity_id, Some(before), None)
+    }
+
+    pub fn restored<M: Serialize>(
+        entity: &'static str,
+        entity_id: impl ToString,
+        before: &M,
+        after: &M,
+    ) -> Self {
+        Self::new(Action::Restore, entity, entity_id, Some(before), Some(after))
+    }
+
+    fn new<M: Serialize>(
+        action: Action,
+        entity: &'static str,
+        entity_id: impl ToString,
+        before: Option<&M>,
+        after: Option<&M>,
+    ) -> Self {
+        let to_json = |model: &M| {
+            serde_json::to_value(model).unwrap_or_else(|err| {
+                error!("Failed to serialize {} for audit log: {:?}", entity, err);
+                Value::Null
+            })
+        };
+
+        Self {
+            action,
+            entity,
+            entity_id: entity_id.to_string(),
+            before: before.map(to_json),
+            after: after.map(to_json),
+        }
+    }
+
+    pub async fn write<C: ConnectionTrait>(
+        self,
+        conn: &C,
+        actor: Option<&AuthUser>,
+    ) -> Result<(), AppError> {
+        let diff = match (&self.before, &self.after) {
+            (Some(before), Some(after)) => Some(diff(before, after)),
+            _ => None,
+        };
+
+        let entry = ActiveModel {
+            id: ActiveValue::NotSet,
+            actor_id: ActiveValue::Set(actor.map(|actor| actor.id)),
+            actor_username: ActiveValue::Set(actor.map(|actor| actor.username.clone())),
+            action: ActiveValue::Set(self.action.as_str().to_string()),
+            entity: ActiveValue::Set(self.entity.to_string()),
+            entity_id: ActiveValue::Set(self.entity_id),
+            before: ActiveValue::Set(self.before.map(redact)),
+            after: ActiveValue::Set(self.after.map(redact)),
+            diff: ActiveValue::Set(diff),
+            request_id: ActiveValue::Set(request_id::current().map(|context| context.request_id)),
+            created_at: ActiveValue::Set(chrono::Utc::now().fixed_offset()),
+        };
+
+        entry.insert(conn).await?;
+        Ok(())
+    }
+}
+
+// 변경된 필드만 { field: { from, to } } 형태로 추출
+// -- redact 대상 필드는 변경 여부만 남긴다.
+fn diff(before: &Value, after: &Value) -> Value {
+    let (Some(before), Some(after)) = (before.as_object(), after.as_object()) else {
+        return json!({ "from": before, "to": after });
+    };
+
+    let changes: Map<String, Value> = after
+        .iter()
+        .filter(|(field, value)| before.get(field.as_str()) != Some(value))
+        .map(|(field, value)| {
+            let change = if REDACTED_FIELDS.contains(&field.as_str()) {
+                json!({ "from": REDACTED, "to": REDACTED })
+            } else {
+                json!({ "from": before.get(field.as_str()), "to": value })
+            };
+            (field.clone(), change)
+        })
+        .collect();
+
+    Value::Object(changes)
+}
+
+fn redact(mut value: Value) -> Value {
+    if let Some(object) = value.as_object_mut() {
+        for field in REDACTED_FIELDS {
+            if let Some(field) = object.get_mut(field) {
+                *field = Value::String(REDACTED.to_string());
+            }
+        }
+    }
+    value
+}
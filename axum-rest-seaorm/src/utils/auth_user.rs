@@ -0,0 +1,64 @@
+use super::app_error::AppError;
+use super::jwt::{Claims, ROLE_ADMIN};
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::request::Parts,
+};
+use tracing::debug;
+
+// jwt::authenticate가 request extensions에 저장한 claims로부터 만들어지는 인증된 사용자 정보
+// -- handler 인자에 AuthUser를 추가하면 호출한 사용자를 알 수 있다.
+#[derive(Clone, Debug)]
+pub struct AuthUser {
+    pub id: i32,
+    pub username: String,
+    pub roles: Vec<String>,
+}
+
+impl AuthUser {
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.has_role(ROLE_ADMIN)
+    }
+
+    // 본인 또는 관리자만 접근 가능한 리소스에 대한 검사
+    pub fn ensure_owner_or_admin(&self, owner_id: i32) -> Result<(), AppError> {
+        if self.id == owner_id || self.is_admin() {
+            Ok(())
+        } else {
+            debug!("User {} is not allowed to modify resource of user {}", self.username, owner_id);
+            Err(AppError::Forbidden("Not allowed to modify this resource".into()))
+        }
+    }
+}
+
+impl From<Claims> for AuthUser {
+    fn from(claims: Claims) -> Self {
+        Self {
+            id: claims.user_id,
+            username: claims.username,
+            roles: claims.roles,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Claims>()
+            .cloned()
+            .map(AuthUser::from)
+            .ok_or_else(|| AppError::Unauthorized("Not authenticated".into()))
+    }
+}
@@ -1,20 +1,38 @@
-use super::app_error;
-use axum::http::StatusCode;
+use super::app_error::AppError;
 use bcrypt::{hash, verify};
 use tracing::error;
 
-const COST: u32 = 12;
+use crate::config::AuthConfig;
 
-pub fn hash_password(password: &str) -> Result<String, app_error::AppError> {
-    hash(password, COST).map_err(|err| {
-        error!("Error hashing password: {:?}", err);
-        app_error::AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Error hashing password")
-    })
+// bcrypt 해시/검증 서비스
+// -- bcrypt는 CPU를 오래 사용하므로 async runtime worker를 막지 않도록 blocking thread에서 실행한다.
+#[derive(Clone, Copy, Debug)]
+pub struct PasswordHasher {
+    cost: u32,
 }
 
-pub fn verify_password(password: &str, hash: &str) -> Result<bool, app_error::AppError> {
-    verify(password, hash).map_err(|err| {
-        error!("Error verifying password: {:?}", err);
-        app_error::AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Error verifying password")
-    })
+impl PasswordHasher {
+    pub fn new(auth: &AuthConfig) -> Self {
+        Self { cost: auth.bcrypt_cost }
+    }
+
+    pub async fn hash(&self, password: String) -> Result<String, AppError> {
+        let cost = self.cost;
+        run_blocking(move || hash(password, cost)).await
+    }
+
+    pub async fn verify(&self, password: String, hash: String) -> Result<bool, AppError> {
+        run_blocking(move || verify(password, &hash)).await
+    }
+}
+
+async fn run_blocking<T, F>(f: F) -> Result<T, AppError>
+where
+    F: FnOnce() -> Result<T, bcrypt::BcryptError> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.map_err(|err| {
+        error!("Password hashing task failed: {:?}", err);
+        AppError::Internal("Error processing password".to_string())
+    })?.map_err(AppError::from)
 }
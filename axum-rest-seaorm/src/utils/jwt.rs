@@ -1,90 +1,180 @@
-use super::app_error;
+use super::app_error::AppError;
 use axum::{
-    http::{HeaderMap, Request, StatusCode},
+    extract::State,
+    http::{HeaderMap, Request},
     middleware::Next,
     response::Response,
     body::Body,
 };
 use chrono::Duration;
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use sea_orm::{DatabaseConnection, EntityTrait};
 use serde::{Deserialize, Serialize};
-use std::env;
+use std::sync::Arc;
 use tracing::{debug, error};
+use uuid::Uuid;
 
+use crate::config::AuthConfig;
+use crate::entities::revoked_token::Entity as RevokedTokenEntity;
+use crate::entities::users::Entity as UsersEntity;
 
-#[derive(Serialize, Deserialize)]
+// users.role 컬럼에 저장되는 역할
+pub const ROLE_ADMIN: &str = "admin";
+pub const ROLE_USER: &str = "user";
+
+// roles는 발급 시점의 값이며, authenticate에서 매 요청마다 DB의 현재 role로 다시 채운다.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Claims {
-    exp: usize,
-    username: String,
+    pub exp: usize,
+    pub iat: usize,
+    pub jti: String,
+    pub user_id: i32,
+    pub username: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
 }
 
-use lazy_static::lazy_static;
+impl Claims {
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
 
-lazy_static! {
-    static ref JWT_SECRET: String = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+// access token 서명/검증에 사용하는 key (시작 시 한 번만 만들어 AppState로 공유)
+#[derive(Clone)]
+pub struct JwtKeys {
+    inner: Arc<JwtKeysInner>,
 }
 
-pub fn create_token(username: String) -> Result<String, app_error::AppError> {
-    let now = chrono::Utc::now();
-    let expires_at = now + Duration::hours(1);
-    let exp = expires_at.timestamp() as usize;
-    let claims = Claims { exp, username };
-    let token_header = Header::default();
-    let key = EncodingKey::from_secret(JWT_SECRET.as_bytes());
-
-    encode(&token_header, &claims, &key).map_err(|err| {
-        error!("Error encoding JWT: {:?}", err);
-        app_error::AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Error encoding JWT")
-    })
+struct JwtKeysInner {
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+    validation: Validation,
+    access_token_ttl: Duration,
 }
 
-pub fn validate_token(token: &str) -> Result<Claims, app_error::AppError> {
-    let binding = token.replace("Bearer ", "");
-    let key = DecodingKey::from_secret(JWT_SECRET.as_bytes());
-    let validation = Validation::new(jsonwebtoken::Algorithm::HS256);
-
-    decode::<Claims>(&binding, &key, &validation).map_err(|err| match err.kind() {
-        jsonwebtoken::errors::ErrorKind::InvalidToken
-        | jsonwebtoken::errors::ErrorKind::InvalidSignature
-        | jsonwebtoken::errors::ErrorKind::ExpiredSignature => {
-            error!("Error validating JWT: {:?}", err);
-            app_error::AppError::new(StatusCode::UNAUTHORIZED, "Not authorized")
+impl JwtKeys {
+    pub fn new(auth: &AuthConfig) -> Self {
+        Self {
+            inner: Arc::new(JwtKeysInner {
+                encoding: EncodingKey::from_secret(auth.jwt_secret.as_bytes()),
+                decoding: DecodingKey::from_secret(auth.jwt_secret.as_bytes()),
+                validation: Validation::new(Algorithm::HS256),
+                access_token_ttl: auth.access_token_ttl(),
+            }),
         }
-        _ => {
-            error!("Error validating JWT: {:?}", err);
-            app_error::AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Error validating JWT")
-        }
-    })
-    .and_then(|decoded| {
+    }
+
+    pub fn access_token_ttl(&self) -> Duration {
+        self.inner.access_token_ttl
+    }
+
+    pub fn create_token(
+        &self,
+        user_id: i32,
+        username: String,
+        roles: Vec<String>,
+    ) -> Result<String, AppError> {
+        let now = chrono::Utc::now();
+        let expires_at = now + self.inner.access_token_ttl;
+        let claims = Claims {
+            exp: expires_at.timestamp() as usize,
+            iat: now.timestamp() as usize,
+            jti: Uuid::new_v4().to_string(),
+            user_id,
+            username,
+            roles,
+        };
+        let token_header = Header::default();
+
+        Ok(encode(&token_header, &claims, &self.inner.encoding)?)
+    }
+
+    pub fn validate_token(&self, token: &str) -> Result<Claims, AppError> {
+        let binding = token.replace("Bearer ", "");
+
+        let decoded = decode::<Claims>(&binding, &self.inner.decoding, &self.inner.validation)?;
+
         if chrono::Utc::now().timestamp() > decoded.claims.exp as i64 {
-            Err(app_error::AppError::new(StatusCode::UNAUTHORIZED, "Token expired"))
+            Err(AppError::Unauthorized("Token expired".into()))
         } else {
             Ok(decoded.claims)
         }
-    })
+    }
+}
+
+// jti가 revocation list(revoked_token 테이블)에 있는지 확인
+pub async fn is_revoked(conn: &DatabaseConnection, jti: &str) -> Result<bool, AppError> {
+    let revoked = RevokedTokenEntity::find_by_id(jti.to_owned()).one(conn).await?;
+    Ok(revoked.is_some())
 }
 
 pub async fn authenticate(
+    State(conn): State<DatabaseConnection>,
+    State(keys): State<JwtKeys>,
     headers: HeaderMap,
-    request: Request<Body>,
+    mut request: Request<Body>,
     next: Next,
-) -> Result<Response, app_error::AppError> {
+) -> Result<Response, AppError> {
     if let Some(value) = headers.get("Authorization") {
         let token = value.to_str().map_err(|err| {
             error!("Error getting Authorization header: {:?}", err);
-            app_error::AppError::new(StatusCode::INTERNAL_SERVER_ERROR, "Error reading token")
+            AppError::BadRequest("Error reading token".into())
         })?;
 
-        let claims = validate_token(token)?;
+        let mut claims = keys.validate_token(token)?;
 
         debug!("Authenticated user: {}", claims.username);
 
         if claims.exp < (chrono::Utc::now().timestamp() as usize) {
-            return Err(app_error::AppError::new(StatusCode::UNAUTHORIZED, "Token expired"));
+            return Err(AppError::Unauthorized("Token expired".into()));
         }
 
+        if is_revoked(&conn, &claims.jti).await? {
+            return Err(AppError::Unauthorized("Token revoked".into()));
+        }
+
+        // token의 roles를 그대로 믿지 않고 현재 role을 다시 읽는다.
+        // -- 강등된 관리자가 access token 만료 전까지 관리자 권한을 유지하지 않도록 하고,
+        //    삭제된 사용자의 token은 거부한다.
+        let user = UsersEntity::find_by_id(claims.user_id)
+            .one(&conn)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("User not found".into()))?;
+        claims.roles = vec![user.role];
+
+        // 이후의 authorization layer에서 사용할 수 있도록 claims를 request extensions에 저장
+        request.extensions_mut().insert(claims);
+
         Ok(next.run(request).await)
     } else {
-        Err(app_error::AppError::new(StatusCode::UNAUTHORIZED, "Not authenticated"))
+        Err(AppError::Unauthorized("Not authenticated".into()))
+    }
+}
+
+// authenticate 이후에 적용되어 claims의 역할을 검사하는 authorization layer
+pub async fn authorize(
+    role: &str,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    let claims = request
+        .extensions()
+        .get::<Claims>()
+        .ok_or_else(|| AppError::Unauthorized("Not authenticated".into()))?;
+
+    if !claims.has_role(role) {
+        debug!("User {} lacks required role: {}", claims.username, role);
+        return Err(AppError::Forbidden("Insufficient permissions".into()));
     }
-}
\ No newline at end of file
+
+    Ok(next.run(request).await)
+}
+
+// 관리자 전용 route에 사용: .delete(handler.layer(middleware::from_fn(jwt::require_admin)))
+pub async fn require_admin(
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    authorize(ROLE_ADMIN, request, next).await
+}
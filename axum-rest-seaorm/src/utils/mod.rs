@@ -1,3 +1,10 @@
 pub mod app_error;
+pub mod audit;
+pub mod auth_user;
 pub mod hash;
 pub mod jwt;
+pub mod money;
+pub mod pagination;
+pub mod refresh_token;
+pub mod request_id;
+pub mod validated_json;
@@ -0,0 +1,153 @@
+use std::{fmt, str::FromStr};
+
+use sea_orm::prelude::Decimal;
+use serde::{de, Deserializer, Serializer};
+use validator::ValidationError;
+
+use super::app_error::{AppError, FieldError};
+
+// currency를 지정하지 않았을 때 사용하는 통화 (통화 도입 이전의 price는 모두 원화)
+pub const DEFAULT_CURRENCY: &str = "KRW";
+
+// 지원하는 ISO 4217 통화 코드와 소수점 이하 자릿수 (minor unit)
+// -- 새 통화가 필요하면 여기에 추가한다. (DB는 소수점 이하 4자리까지 저장)
+const CURRENCIES: &[(&str, u32)] = &[
+    ("KRW", 0),
+    ("USD", 2),
+    ("EUR", 2),
+    ("JPY", 0),
+    ("GBP", 2),
+    ("CNY", 2),
+    ("HKD", 2),
+    ("SGD", 2),
+    ("AUD", 2),
+    ("CAD", 2),
+    ("CHF", 2),
+    ("TWD", 2),
+];
+
+// numeric(19, 4)의 정수부는 15자리
+const MAX_INTEGER_DIGITS: u32 = 15;
+
+pub fn minor_units(currency: &str) -> Option<u32> {
+    CURRENCIES
+        .iter()
+        .find(|(code, _)| *code == currency)
+        .map(|(_, units)| *units)
+}
+
+// validator: 지원하는 통화 코드인지 확인
+pub fn currency_code(value: &str) -> Result<(), ValidationError> {
+    if minor_units(value).is_none() {
+        return Err(ValidationError::new("currency")
+            .with_message("must be a supported ISO 4217 currency code (e.g. KRW, USD)".into()));
+    }
+    Ok(())
+}
+
+// validator: 0 이상이고 DB에 저장할 수 있는 금액인지 확인
+pub fn non_negative_amount(value: &Decimal) -> Result<(), ValidationError> {
+    if value.is_sign_negative() && !value.is_zero() {
+        return Err(ValidationError::new("amount").with_message("must not be negative".into()));
+    }
+    if !fits_column(value) {
+        return Err(ValidationError::new("amount").with_message("is too large".into()));
+    }
+    Ok(())
+}
+
+// 금액 column(numeric(19, 4))에 저장할 수 있는지 확인 (정수부가 MAX_INTEGER_DIGITS 이하)
+pub fn fits_column(value: &Decimal) -> bool {
+    value.abs().trunc() < Decimal::from(10_i64.pow(MAX_INTEGER_DIGITS))
+}
+
+// 금액의 소수점 이하 자릿수가 통화의 minor unit을 넘지 않는지 확인 (예: KRW는 정수만 허용)
+// -- price와 currency가 따로 변경될 수 있으므로 두 값을 합친 뒤에 검사한다.
+pub fn check_scale(field: &str, amount: Decimal, currency: &str) -> Result<(), AppError> {
+    let units = minor_units(currency).unwrap_or(0);
+    if amount.normalize().scale() > units {
+        return Err(AppError::Validation(
+            "Invalid request body".into(),
+            vec![FieldError::new(
+                field,
+                format!("{} allows at most {} decimal places", currency, units),
+            )],
+        ));
+    }
+    Ok(())
+}
+
+// 금액은 JSON number의 부동소수점 오차를 피하기 위해 문자열로 주고받는다. (예: "19.99")
+// -- 이전 API와 호환되도록 정수 number는 허용하지만 소수가 있는 number는 거부한다.
+pub mod amount {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        // DB에서 읽은 값은 numeric(19, 4) scale이므로 "1200.0000" 대신 "1200"으로 응답
+        serializer.collect_str(&value.normalize())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+// Option<Decimal> 필드용 (요청 body, query string)
+// -- JSON null은 값이 없는 것(None)으로 처리한다.
+pub mod optional_amount {
+    use super::*;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Decimal>, D::Error> {
+        deserializer.deserialize_option(OptionalAmountVisitor)
+    }
+
+    struct OptionalAmountVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionalAmountVisitor {
+        type Value = Option<Decimal>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            AmountVisitor.expecting(f)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(AmountVisitor).map(Some)
+        }
+    }
+}
+
+struct AmountVisitor;
+
+impl de::Visitor<'_> for AmountVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal amount as a string (e.g. \"19.99\")")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Decimal, E> {
+        Decimal::from_str(value).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Decimal, E> {
+        Err(E::invalid_type(de::Unexpected::Float(value), &self))
+    }
+}
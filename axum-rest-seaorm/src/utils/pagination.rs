@@ -0,0 +1,385 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, OriginalUri},
+    http::{header, request::Parts, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{
+    prelude::Decimal, sea_query::ColumnType, ColumnTrait, ConnectionTrait, EntityTrait,
+    ModelTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select,
+    Value,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
+
+use super::app_error::AppError;
+use crate::entities::{audit_log, category, inventory_adjustment, orders, product, users};
+
+pub const DEFAULT_PER_PAGE: u64 = 20;
+pub const MAX_PER_PAGE: u64 = 100;
+
+// OpenAPI 문서용 공통 query parameter
+// -- 실제 파싱은 ListQuery extractor에서 수행한다.
+#[allow(dead_code)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PaginationParams {
+    /// 페이지 번호 (1부터 시작, offset pagination)
+    #[param(example = 1, minimum = 1)]
+    page: Option<u64>,
+    /// 페이지 크기 (기본 20, 최대 100)
+    #[param(example = 20, minimum = 1, maximum = 100)]
+    per_page: Option<u64>,
+    /// keyset pagination cursor: 첫 페이지는 빈 값, 이후에는 응답의 next_cursor 값을 전달 (sort와 함께 사용할 수 없음)
+    cursor: Option<String>,
+    /// 정렬 필드 목록, `-` 접두사는 내림차순 (예: `price,-title`)
+    #[param(example = "price,-title")]
+    sort: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RangeOp {
+    Gte,
+    Lte,
+    Gt,
+    Lt,
+}
+
+impl RangeOp {
+    const SUFFIXES: [(&'static str, RangeOp); 4] = [
+        ("_gte", RangeOp::Gte),
+        ("_lte", RangeOp::Lte),
+        ("_gt", RangeOp::Gt),
+        ("_lt", RangeOp::Lt),
+    ];
+}
+
+// 목록 조회 endpoint에서 공통으로 사용하는 pagination / sort / range filter query extractor
+// -- ?page=2&per_page=50&sort=price,-title&price_gte=100&price_lte=2000
+// -- ?cursor=&per_page=50 (keyset pagination)
+#[derive(Debug)]
+pub struct ListQuery {
+    pub page: u64,
+    pub per_page: u64,
+    pub cursor: Option<String>,
+    sort: Vec<(String, Order)>,
+    ranges: Vec<(String, RangeOp, String)>,
+    path: String,
+    pairs: Vec<(String, String)>,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ListQuery
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let pairs: Vec<(String, String)> =
+            serde_urlencoded::from_str(parts.uri.query().unwrap_or_default())
+                .map_err(|err| AppError::BadRequest(format!("Invalid query string: {}", err)))?;
+        // nest된 router에서는 parts.uri의 prefix(/api/v1)가 잘려 있으므로 원래 요청 URI를 사용한다.
+        let path = parts
+            .extensions
+            .get::<OriginalUri>()
+            .map_or_else(|| parts.uri.path(), |uri| uri.path())
+            .to_string();
+
+        let mut query = ListQuery {
+            page: 1,
+            per_page: DEFAULT_PER_PAGE,
+            cursor: None,
+            sort: Vec::new(),
+            ranges: Vec::new(),
+            path,
+            pairs: pairs.clone(),
+        };
+
+        for (key, value) in pairs {
+            match key.as_str() {
+                "page" => {
+                    query.page = value
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|page| *page >= 1)
+                        .ok_or_else(|| AppError::BadRequest("page must be a positive integer".into()))?;
+                }
+                "per_page" => {
+                    query.per_page = value
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|per_page| (1..=MAX_PER_PAGE).contains(per_page))
+                        .ok_or_else(|| {
+                            AppError::BadRequest(format!("per_page must be between 1 and {}", MAX_PER_PAGE))
+                        })?;
+                }
+                "cursor" => query.cursor = Some(value),
+                "sort" => {
+                    for field in value.split(',').map(str::trim).filter(|field| !field.is_empty()) {
+                        match field.strip_prefix('-') {
+                            Some(field) => query.sort.push((field.to_string(), Order::Desc)),
+                            None => query.sort.push((field.to_string(), Order::Asc)),
+                        }
+                    }
+                }
+                _ => {
+                    if let Some((field, op)) = RangeOp::SUFFIXES
+                        .iter()
+                        .find_map(|(suffix, op)| key.strip_suffix(suffix).map(|field| (field, *op)))
+                    {
+                        query.ranges.push((field.to_string(), op, value));
+                    }
+                }
+            }
+        }
+
+        // offset((page - 1) * per_page)은 DB에 i64로 bind되므로 그 범위를 넘는 page는 거부한다.
+        (query.page - 1)
+            .checked_mul(query.per_page)
+            .filter(|offset| i64::try_from(*offset).is_ok())
+            .ok_or_else(|| AppError::BadRequest("page is too large".into()))?;
+
+        if query.cursor.is_some() && !query.sort.is_empty() {
+            return Err(AppError::BadRequest("sort cannot be combined with cursor pagination".into()));
+        }
+
+        Ok(query)
+    }
+}
+
+// entity별로 정렬/필터가 허용되는 컬럼과 keyset pagination에 사용할 컬럼
+pub struct ListSpec<C: 'static> {
+    pub sortable: &'static [C],
+    pub filterable: &'static [C],
+    // sort가 지정되지 않았을 때의 정렬 (오름차순)
+    pub default_sort: &'static [C],
+    // 마지막 정렬 기준이자 keyset cursor 컬럼 (unique 해야 함)
+    pub cursor: C,
+}
+
+fn find_column<C: ColumnTrait>(columns: &[C], name: &str) -> Option<C> {
+    columns.iter().copied().find(|column| column.as_str() == name)
+}
+
+// 컬럼 타입에 맞게 query 문자열을 DB 값으로 변환
+fn parse_value<C: ColumnTrait>(column: C, raw: &str) -> Result<Value, AppError> {
+    let invalid = || AppError::BadRequest(format!("Invalid value for {}: {}", column.as_str(), raw));
+
+    let value = match column.def().get_column_type() {
+        ColumnType::TinyInteger | ColumnType::SmallInteger | ColumnType::Integer => {
+            Value::from(raw.parse::<i32>().map_err(|_| invalid())?)
+        }
+        ColumnType::BigInteger => Value::from(raw.parse::<i64>().map_err(|_| invalid())?),
+        ColumnType::Float | ColumnType::Double => Value::from(raw.parse::<f64>().map_err(|_| invalid())?),
+        ColumnType::Decimal(_) | ColumnType::Money(_) => {
+            Value::from(Decimal::from_str(raw).map_err(|_| invalid())?)
+        }
+        ColumnType::Boolean => Value::from(raw.parse::<bool>().map_err(|_| invalid())?),
+        ColumnType::Timestamp | ColumnType::TimestampWithTimeZone | ColumnType::DateTime => {
+            Value::from(DateTime::<FixedOffset>::parse_from_rfc3339(raw).map_err(|_| invalid())?)
+        }
+        _ => Value::from(raw.to_string()),
+    };
+
+    Ok(value)
+}
+
+fn cursor_to_string(value: Value) -> Option<String> {
+    match value {
+        Value::TinyInt(Some(v)) => Some(v.to_string()),
+        Value::SmallInt(Some(v)) => Some(v.to_string()),
+        Value::Int(Some(v)) => Some(v.to_string()),
+        Value::BigInt(Some(v)) => Some(v.to_string()),
+        Value::String(Some(v)) => Some(*v),
+        _ => None,
+    }
+}
+
+// 목록 조회 응답 envelope
+#[derive(Serialize, ToSchema)]
+#[aliases(
+    UserPage = Page<users::Model>,
+    ProductPage = Page<product::Model>,
+    CategoryPage = Page<category::Model>,
+    CategorySummaryPage = Page<crate::api::category::CategorySummary>,
+    AuditLogPage = Page<audit_log::Model>,
+    InventoryAdjustmentPage = Page<inventory_adjustment::Model>,
+    OrderPage = Page<orders::Model>
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    // 필터 조건에 맞는 전체 row 수
+    #[schema(example = 1234)]
+    pub total: u64,
+    // offset pagination에서만 설정됨
+    #[schema(example = 1)]
+    pub page: Option<u64>,
+    #[schema(example = 20)]
+    pub per_page: u64,
+    #[schema(example = 62)]
+    pub total_pages: u64,
+    // keyset pagination에서 다음 페이지를 요청할 때 전달할 cursor
+    pub next_cursor: Option<String>,
+    #[serde(skip)]
+    links: Vec<(String, String)>,
+}
+
+impl<T> Page<T> {
+    // items만 다른 타입으로 변환 (pagination 정보와 Link 헤더는 그대로 유지)
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            per_page: self.per_page,
+            total_pages: self.total_pages,
+            next_cursor: self.next_cursor,
+            links: self.links,
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for Page<T> {
+    fn into_response(self) -> Response {
+        let link = self
+            .links
+            .iter()
+            .map(|(rel, url)| format!("<{}>; rel=\"{}\"", url, rel))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let total = self.total;
+
+        let mut response = Json(self).into_response();
+        if let Ok(value) = HeaderValue::from_str(&link) {
+            if !link.is_empty() {
+                response.headers_mut().insert(header::LINK, value);
+            }
+        }
+        response
+            .headers_mut()
+            .insert("x-total-count", HeaderValue::from(total));
+        response
+    }
+}
+
+impl ListQuery {
+    // page/cursor 값만 바꾼 현재 요청의 URL (Link 헤더용)
+    fn link(&self, key: &str, value: &str) -> String {
+        let mut pairs: Vec<(&str, &str)> = self
+            .pairs
+            .iter()
+            .filter(|(k, _)| k != "page" && k != "cursor")
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        pairs.push((key, value));
+
+        let query = serde_urlencoded::to_string(&pairs).unwrap_or_default();
+        format!("{}?{}", self.path, query)
+    }
+
+    // select에 range filter / 정렬 / pagination을 적용하여 실행
+    pub async fn fetch<E, Db>(
+        &self,
+        conn: &Db,
+        select: Select<E>,
+        spec: &ListSpec<E::Column>,
+    ) -> Result<Page<E::Model>, AppError>
+    where
+        E: EntityTrait,
+        E::Model: Sync,
+        Db: ConnectionTrait,
+    {
+        let mut select = select;
+
+        for (field, op, raw) in &self.ranges {
+            let column = find_column(spec.filterable, field)
+                .ok_or_else(|| AppError::BadRequest(format!("Cannot filter by field: {}", field)))?;
+            let value = parse_value(column, raw)?;
+            select = select.filter(match op {
+                RangeOp::Gte => column.gte(value),
+                RangeOp::Lte => column.lte(value),
+                RangeOp::Gt => column.gt(value),
+                RangeOp::Lt => column.lt(value),
+            });
+        }
+
+        let total = select.clone().count(conn).await?;
+        let total_pages = total.div_ceil(self.per_page);
+
+        if let Some(cursor) = &self.cursor {
+            // keyset pagination: cursor 컬럼 기준으로 cursor 이후의 row를 조회
+            if !cursor.is_empty() {
+                select = select.filter(spec.cursor.gt(parse_value(spec.cursor, cursor)?));
+            }
+
+            let items = select
+                .order_by(spec.cursor, Order::Asc)
+                .limit(self.per_page)
+                .all(conn)
+                .await?;
+
+            let next_cursor = if items.len() as u64 == self.per_page {
+                items.last().and_then(|item| cursor_to_string(item.get(spec.cursor)))
+            } else {
+                None
+            };
+
+            let links = next_cursor
+                .iter()
+                .map(|next| ("next".to_string(), self.link("cursor", next)))
+                .collect();
+
+            return Ok(Page {
+                items,
+                total,
+                page: None,
+                per_page: self.per_page,
+                total_pages,
+                next_cursor,
+                links,
+            });
+        }
+
+        for (field, order) in &self.sort {
+            let column = find_column(spec.sortable, field)
+                .ok_or_else(|| AppError::BadRequest(format!("Cannot sort by field: {}", field)))?;
+            select = select.order_by(column, order.clone());
+        }
+        if self.sort.is_empty() {
+            for column in spec.default_sort {
+                select = select.order_by(*column, Order::Asc);
+            }
+        }
+        // 페이지 간 순서가 흔들리지 않도록 unique 컬럼을 마지막 정렬 기준으로 추가
+        select = select.order_by(spec.cursor, Order::Asc);
+
+        let items = select
+            .offset((self.page - 1) * self.per_page)
+            .limit(self.per_page)
+            .all(conn)
+            .await?;
+
+        let mut links = vec![("first".to_string(), self.link("page", "1"))];
+        if self.page > 1 {
+            links.push(("prev".to_string(), self.link("page", &(self.page - 1).to_string())));
+        }
+        if self.page < total_pages {
+            links.push(("next".to_string(), self.link("page", &(self.page + 1).to_string())));
+        }
+        links.push(("last".to_string(), self.link("page", &total_pages.max(1).to_string())));
+
+        Ok(Page {
+            items,
+            total,
+            page: Some(self.page),
+            per_page: self.per_page,
+            total_pages,
+            next_cursor: None,
+            links,
+        })
+    }
+}
@@ -0,0 +1,143 @@
+use super::app_error::AppError;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait,
+    DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait,
+};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::config::AuthConfig;
+use crate::entities::refresh_token::{ActiveModel, Column, Entity, Model};
+
+// 클라이언트에게 전달하는 원문 토큰은 DB에 저장하지 않고 해시만 보관한다.
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+// 새로운 refresh token을 발급하여 저장하고 (원문 토큰, 저장된 row)를 반환
+// -- family_id가 None이면 새로운 token family(로그인 세션)를 시작한다.
+pub async fn issue<C: ConnectionTrait>(
+    conn: &C,
+    auth: &AuthConfig,
+    user_id: i32,
+    family_id: Option<String>,
+) -> Result<(String, Model), AppError> {
+    let token = generate_token();
+    let now = Utc::now();
+
+    let new_token = ActiveModel {
+        id: ActiveValue::NotSet,
+        user_id: ActiveValue::Set(user_id),
+        token_hash: ActiveValue::Set(hash_token(&token)),
+        family_id: ActiveValue::Set(family_id.unwrap_or_else(|| Uuid::new_v4().to_string())),
+        expires_at: ActiveValue::Set((now + auth.refresh_token_ttl()).fixed_offset()),
+        revoked_at: ActiveValue::Set(None),
+        replaced_by: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(now.fixed_offset()),
+    };
+
+    let model = new_token.insert(conn).await?;
+    Ok((token, model))
+}
+
+// 제시된 refresh token을 폐기하고 같은 family의 새 토큰을 발급한다.
+// -- 이미 회전(rotation)된 토큰이 다시 제시되면 탈취로 간주하여 family 전체를 폐기한다.
+// -- family 폐기는 트랜잭션 밖에서 수행되어야 하므로 DatabaseConnection을 직접 받는다.
+pub async fn rotate(
+    conn: &DatabaseConnection,
+    auth: &AuthConfig,
+    token: &str,
+) -> Result<(String, Model), AppError> {
+    let current = Entity::find()
+        .filter(Column::TokenHash.eq(hash_token(token)))
+        .one(conn)
+        .await
+        ?
+        .ok_or_else(|| AppError::Unauthorized("Invalid refresh token".into()))?;
+
+    if current.revoked_at.is_some() {
+        if current.replaced_by.is_some() {
+            return Err(reuse_detected(conn, &current).await);
+        }
+        return Err(AppError::Unauthorized("Refresh token revoked".into()));
+    }
+
+    if current.expires_at < Utc::now() {
+        return Err(AppError::Unauthorized("Refresh token expired".into()));
+    }
+
+    let txn = conn.begin().await?;
+    let (new_token, new_model) = issue(&txn, auth, current.user_id, Some(current.family_id.clone())).await?;
+
+    // 동시에 같은 토큰으로 refresh 요청이 들어온 경우 한쪽만 성공하도록
+    // revoked_at IS NULL 조건으로 갱신한다.
+    let result = Entity::update_many()
+        .col_expr(Column::RevokedAt, Expr::value(Utc::now().fixed_offset()))
+        .col_expr(Column::ReplacedBy, Expr::value(new_model.id))
+        .filter(Column::Id.eq(current.id))
+        .filter(Column::RevokedAt.is_null())
+        .exec(&txn)
+        .await
+        ?;
+
+    if result.rows_affected != 1 {
+        txn.rollback().await?;
+        return Err(reuse_detected(conn, &current).await);
+    }
+
+    txn.commit().await?;
+    Ok((new_token, new_model))
+}
+
+async fn reuse_detected(conn: &DatabaseConnection, token: &Model) -> AppError {
+    warn!(
+        "Refresh token reuse detected (user_id: {}, family: {})",
+        token.user_id, token.family_id
+    );
+    match revoke_family(conn, &token.family_id).await {
+        Ok(()) => AppError::Unauthorized("Refresh token reuse detected".into()),
+        Err(err) => err,
+    }
+}
+
+// family에 속한 모든 유효한 refresh token을 폐기
+pub async fn revoke_family<C: ConnectionTrait>(conn: &C, family_id: &str) -> Result<(), AppError> {
+    Entity::update_many()
+        .col_expr(Column::RevokedAt, Expr::value(Utc::now().fixed_offset()))
+        .filter(Column::FamilyId.eq(family_id))
+        .filter(Column::RevokedAt.is_null())
+        .exec(conn)
+        .await
+        ?;
+
+    Ok(())
+}
+
+// 원문 토큰으로 저장된 refresh token을 찾는다.
+pub async fn find<C: ConnectionTrait>(conn: &C, token: &str) -> Result<Model, AppError> {
+    Entity::find()
+        .filter(Column::TokenHash.eq(hash_token(token)))
+        .one(conn)
+        .await
+        ?
+        .ok_or_else(|| AppError::Unauthorized("Invalid refresh token".into()))
+}
+
+// 사용자의 모든 유효한 refresh token을 폐기 (password 변경 등 모든 로그인 세션을 끊어야 할 때)
+pub async fn revoke_all_for_user<C: ConnectionTrait>(conn: &C, user_id: i32) -> Result<(), AppError> {
+    Entity::update_many()
+        .col_expr(Column::RevokedAt, Expr::value(Utc::now().fixed_offset()))
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::RevokedAt.is_null())
+        .exec(conn)
+        .await
+        ?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+pub static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+#[derive(Clone, Debug)]
+pub struct RequestContext {
+    pub request_id: String,
+    pub path: String,
+}
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+// 현재 처리 중인 요청의 context (request id 미들웨어 밖에서는 None)
+pub fn current() -> Option<RequestContext> {
+    REQUEST_CONTEXT.try_with(|context| context.clone()).ok()
+}
+
+// 요청마다 request id를 부여하고 (클라이언트가 보낸 x-request-id가 있으면 그대로 사용)
+// 응답 헤더와 에러 응답 본문에서 같은 값을 사용할 수 있도록 task-local에 저장한다.
+pub async fn propagate(mut request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty() && value.len() <= 128)
+        .map(str::to_owned)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let header_value = HeaderValue::from_str(&request_id)
+        .unwrap_or_else(|_| HeaderValue::from_static("invalid"));
+    request.headers_mut().insert(X_REQUEST_ID.clone(), header_value.clone());
+
+    let context = RequestContext {
+        request_id,
+        path: request.uri().path().to_string(),
+    };
+
+    let mut response = REQUEST_CONTEXT.scope(context, next.run(request)).await;
+    response.headers_mut().insert(X_REQUEST_ID.clone(), header_value);
+    response
+}
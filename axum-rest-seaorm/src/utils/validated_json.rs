@@ -0,0 +1,121 @@
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use super::app_error::{AppError, FieldError};
+
+// 요청 본문을 역직렬화한 뒤 validator 제약 조건을 검사하는 extractor
+// -- 검증에 실패하면 필드별 errors 배열과 함께 422를 응답한다.
+// -- Option 필드의 제약 조건은 값이 전달된 경우에만 검사하므로,
+//    POST/PUT처럼 필드가 필수인 경우에는 require_fields로 별도 확인한다.
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(request, state)
+            .await
+            .map_err(|rejection| match rejection {
+                // 필드 타입 불일치 등 JSON 구조는 올바르지만 값이 잘못된 경우
+                JsonRejection::JsonDataError(err) => {
+                    AppError::Validation(err.body_text(), Vec::new())
+                }
+                rejection => AppError::BadRequest(rejection.body_text()),
+            })?;
+
+        value
+            .validate()
+            .map_err(|errors| AppError::Validation("Invalid request body".into(), field_errors(&errors)))?;
+
+        Ok(ValidatedJson(value))
+    }
+}
+
+pub(crate) fn field_errors(errors: &ValidationErrors) -> Vec<FieldError> {
+    let mut field_errors = Vec::new();
+    collect_field_errors("", errors, &mut field_errors);
+
+    // HashMap 순서에 관계없이 항상 같은 순서로 응답
+    field_errors.sort_by(|a, b| a.field.cmp(&b.field));
+    field_errors
+}
+
+// nested 필드의 에러는 경로를 붙여서 펼친다. (예: items[0].quantity)
+fn collect_field_errors(prefix: &str, errors: &ValidationErrors, out: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                out.extend(errors.iter().map(|error| FieldError::new(path.clone(), describe(error))));
+            }
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(&path, errors, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_field_errors(&format!("{}[{}]", path, index), errors, out);
+                }
+            }
+        }
+    }
+}
+
+// 제약 조건에 message가 지정되지 않은 경우 code와 params로 메시지를 만든다.
+fn describe(error: &ValidationError) -> String {
+    if let Some(message) = &error.message {
+        return message.to_string();
+    }
+
+    let param = |name: &str| error.params.get(name).map(|value| value.to_string());
+    match error.code.as_ref() {
+        "length" => match (param("min"), param("max")) {
+            (Some(min), Some(max)) => format!("length must be between {} and {}", min, max),
+            (Some(min), None) => format!("length must be at least {}", min),
+            (None, Some(max)) => format!("length must be at most {}", max),
+            (None, None) => "invalid length".to_string(),
+        },
+        "range" => match (param("min"), param("max")) {
+            (Some(min), Some(max)) => format!("must be between {} and {}", min, max),
+            (Some(min), None) => format!("must be at least {}", min),
+            (None, Some(max)) => format!("must be at most {}", max),
+            (None, None) => "out of range".to_string(),
+        },
+        "required" => "is required".to_string(),
+        code => format!("failed {} validation", code),
+    }
+}
+
+// 필수 필드 확인: (필드 이름, 값 전달 여부)
+pub fn require_fields(message: &str, fields: &[(&str, bool)]) -> Result<(), AppError> {
+    let errors: Vec<FieldError> = fields
+        .iter()
+        .filter(|(_, present)| !present)
+        .map(|(field, _)| FieldError::new(*field, "is required"))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::Validation(message.to_string(), errors))
+    }
+}
+
+// 공백만으로 이루어진 문자열 거부
+pub fn not_blank(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(ValidationError::new("blank").with_message("must not be blank".into()));
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use common::TestApp;
+use serde_json::{json, Value};
+
+const BOUNDARY: &str = "test-boundary";
+
+fn entries(response: &common::TestResponse) -> &Vec<Value> {
+    response.body["items"].as_array().unwrap()
+}
+
+#[tokio::test]
+async fn audit_log_requires_admin() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+
+    assert_eq!(app.get("/api/v1/audit", None).await.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(app.get("/api/v1/audit", Some(&token)).await.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn audit_log_records_changes_with_actor() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let category = app.create_category(&admin, "Books").await;
+    let product = app.create_product(&admin, "Novel", 20, "Books").await;
+    let uri = format!("/api/v1/products/{}", product["id"]);
+    app.request(Method::PATCH, &uri, Some(&admin), Some(json!({ "price": 25 }))).await;
+    app.request(Method::DELETE, &uri, Some(&admin), None).await;
+
+    let response = app
+        .get(&format!("/api/v1/audit?entity=category&entity_id={}", category["id"]), Some(&admin))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    let created = &entries(&response)[0];
+    assert_eq!(created["action"], "create");
+    assert_eq!(created["actor_username"], "admin");
+    assert!(created["before"].is_null());
+    assert_eq!(created["after"]["name"], "Books");
+
+    let response = app
+        .get(&format!("/api/v1/audit?entity=product&entity_id={}&sort=id", product["id"]), Some(&admin))
+        .await;
+    let actions: Vec<&str> = entries(&response)
+        .iter()
+        .map(|entry| entry["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, ["create", "update", "delete"]);
+    // update는 바뀐 필드만 diff에 남는다.
+    let diff = entries(&response)[1]["diff"].as_object().unwrap();
+    assert_eq!(diff["price"], json!({ "from": "20", "to": "25" }));
+    assert!(!diff.contains_key("title"));
+
+    let response = app.get("/api/v1/audit?action=delete", Some(&admin)).await;
+    assert_eq!(response.body["total"], 1);
+    assert_eq!(entries(&response)[0]["before"]["title"], "Novel");
+}
+
+#[tokio::test]
+async fn audit_log_redacts_passwords() {
+    let app = TestApp::new().await;
+    let (id, token) = app.user("alice").await;
+    app.request(
+        Method::PUT,
+        &format!("/api/v1/users/{}", id),
+        Some(&token),
+        Some(json!({ "username": "alice", "password": "new-password1" })),
+    )
+    .await;
+
+    let admin = app.admin_token().await;
+    let response = app
+        .get(&format!("/api/v1/audit?entity=users&entity_id={}&sort=id", id), Some(&admin))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    let entries = entries(&response);
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0]["action"], "create");
+    assert_eq!(entries[0]["after"]["password"], "[redacted]");
+
+    assert_eq!(entries[1]["action"], "update");
+    assert_eq!(entries[1]["actor_username"], "alice");
+    assert_eq!(entries[1]["before"]["password"], "[redacted]");
+    assert_eq!(entries[1]["after"]["password"], "[redacted]");
+    // password가 바뀌었다는 사실만 남긴다.
+    assert_eq!(entries[1]["diff"]["password"], json!({ "from": "[redacted]", "to": "[redacted]" }));
+    // bcrypt hash가 어디에도 남지 않아야 한다.
+    assert!(!response.body.to_string().contains("$2"));
+}
+
+#[tokio::test]
+async fn audit_entries_roll_back_with_the_change() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    // category 자동 생성과 audit 기록 후 row 오류로 전체 rollback
+    let csv = "title,price,category\nLaptop,1200,Electronics\nMouse,-1,Electronics\n";
+    let response = app
+        .request_raw(
+            Method::POST,
+            "/api/v1/products/import",
+            Some(&admin),
+            &format!("multipart/form-data; boundary={}", BOUNDARY),
+            format!(
+                "--{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"products.csv\"\r\n\r\n{c}\r\n--{b}--\r\n",
+                b = BOUNDARY,
+                c = csv
+            ),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", response.body);
+
+    for entity in ["category", "product"] {
+        let response = app.get(&format!("/api/v1/audit?entity={}", entity), Some(&admin)).await;
+        assert_eq!(response.body["total"], 0, "{}", entity);
+    }
+
+    // 사용 중이라 거부된 삭제도 기록되지 않는다.
+    app.create_category(&admin, "Books").await;
+    app.create_product(&admin, "Novel", 20, "Books").await;
+    let response = app
+        .request(Method::DELETE, "/api/v1/categories/Books", Some(&admin), None)
+        .await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    let response = app.get("/api/v1/audit?action=delete", Some(&admin)).await;
+    assert_eq!(response.body["total"], 0);
+}
@@ -0,0 +1,239 @@
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use axum_rest_seaorm::entities::users;
+use common::TestApp;
+use sea_orm::{sea_query::Expr, ColumnTrait, EntityTrait, QueryFilter};
+use serde_json::json;
+
+#[tokio::test]
+async fn signup_creates_user_with_location() {
+    let app = TestApp::new().await;
+
+    let response = app.signup("alice", "password123").await;
+
+    assert_eq!(response.status, StatusCode::CREATED);
+    assert_eq!(response.body["username"], "alice");
+    assert_eq!(response.body["role"], "user");
+    let id = response.body["id"].as_i64().unwrap();
+    assert_eq!(response.header(header::LOCATION), Some(format!("/api/v1/users/{}", id).as_str()));
+}
+
+#[tokio::test]
+async fn signup_rejects_duplicate_username() {
+    let app = TestApp::new().await;
+    app.signup("alice", "password123").await;
+
+    let response = app.signup("alice", "password456").await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["code"], "conflict");
+}
+
+#[tokio::test]
+async fn signup_validates_fields() {
+    let app = TestApp::new().await;
+
+    let response = app.signup("a", "short").await;
+
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.body["code"], "validation_failed");
+    let fields: Vec<&str> = response.body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["field"].as_str().unwrap())
+        .collect();
+    assert!(fields.contains(&"username"));
+    assert!(fields.contains(&"password"));
+}
+
+#[tokio::test]
+async fn signup_limits_password_to_72_bytes() {
+    let app = TestApp::new().await;
+
+    let ascii = format!("a1{}", "x".repeat(70));
+    assert_eq!(app.signup("ascii", &ascii).await.status, StatusCode::CREATED);
+    let response = app.signup("longer", &format!("{}x", ascii)).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // 30자이지만 UTF-8로 91 bytes이므로 bcrypt에서 잘리지 않도록 거부
+    let response = app.signup("korean", &format!("1{}", "비".repeat(30))).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.body["errors"][0]["field"], "password");
+    assert_eq!(response.body["errors"][0]["message"], "length must be at most 72 bytes");
+}
+
+#[tokio::test]
+async fn signup_rejects_malformed_json() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .request(Method::POST, "/api/v1/auth/signup", None, Some(json!("not an object")))
+        .await;
+
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn login_returns_token_pair() {
+    let app = TestApp::new().await;
+    app.signup("alice", "password123").await;
+
+    let response = app.login("alice", "password123").await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["token_type"], "Bearer");
+    assert!(response.body["access_token"].as_str().is_some());
+    assert!(response.body["refresh_token"].as_str().is_some());
+    assert!(response.body["expires_in"].as_i64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn login_rejects_wrong_password() {
+    let app = TestApp::new().await;
+    app.signup("alice", "password123").await;
+
+    let response = app.login("alice", "password999").await;
+
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(response.body["code"], "unauthorized");
+}
+
+#[tokio::test]
+async fn login_rejects_unknown_user() {
+    let app = TestApp::new().await;
+
+    let response = app.login("nobody", "password123").await;
+
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn protected_routes_require_valid_token() {
+    let app = TestApp::new().await;
+
+    let missing = app.get("/api/v1/products", None).await;
+    let invalid = app.get("/api/v1/products", Some("not-a-jwt")).await;
+
+    assert_eq!(missing.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(invalid.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(missing.header(header::CONTENT_TYPE), Some("application/problem+json"));
+}
+
+#[tokio::test]
+async fn refresh_rotates_token_and_detects_reuse() {
+    let app = TestApp::new().await;
+    app.signup("alice", "password123").await;
+    let login = app.login("alice", "password123").await;
+    let refresh_token = login.body["refresh_token"].as_str().unwrap().to_string();
+
+    let refreshed = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": refresh_token })),
+        )
+        .await;
+    assert_eq!(refreshed.status, StatusCode::OK);
+    assert_ne!(refreshed.body["refresh_token"], refresh_token.as_str());
+
+    // 이미 회전된 refresh token을 다시 사용하면 거부된다.
+    let reused = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": refresh_token })),
+        )
+        .await;
+    assert_eq!(reused.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn logout_revokes_access_and_refresh_tokens() {
+    let app = TestApp::new().await;
+    app.signup("alice", "password123").await;
+    let login = app.login("alice", "password123").await;
+    let access_token = login.body["access_token"].as_str().unwrap().to_string();
+    let refresh_token = login.body["refresh_token"].as_str().unwrap().to_string();
+
+    let logout = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/logout",
+            Some(&access_token),
+            Some(json!({ "refresh_token": refresh_token })),
+        )
+        .await;
+    assert_eq!(logout.status, StatusCode::OK);
+
+    let response = app.get("/api/v1/products", Some(&access_token)).await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+
+    let refreshed = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": refresh_token })),
+        )
+        .await;
+    assert_eq!(refreshed.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn logout_rejects_access_token_of_another_user() {
+    let app = TestApp::new().await;
+    app.signup("alice", "password123").await;
+    app.signup("bob", "password123").await;
+    let alice = app.login("alice", "password123").await;
+    let bob = app.login("bob", "password123").await;
+    let bob_access = bob.body["access_token"].as_str().unwrap().to_string();
+
+    let logout = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/logout",
+            Some(&bob_access),
+            Some(json!({ "refresh_token": alice.body["refresh_token"] })),
+        )
+        .await;
+    assert_eq!(logout.status, StatusCode::FORBIDDEN);
+
+    // 어느 쪽 token도 폐기되지 않는다.
+    assert_eq!(app.get("/api/v1/products", Some(&bob_access)).await.status, StatusCode::OK);
+    let refreshed = app
+        .request(
+            Method::POST,
+            "/api/v1/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": alice.body["refresh_token"] })),
+        )
+        .await;
+    assert_eq!(refreshed.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn role_changes_apply_to_issued_tokens() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (alice_id, alice) = app.user("alice").await;
+
+    // access token은 그대로 두고 DB의 role만 강등
+    users::Entity::update_many()
+        .col_expr(users::Column::Role, Expr::value("user"))
+        .filter(users::Column::Username.eq(common::ADMIN_USERNAME))
+        .exec(&app.state.conn)
+        .await
+        .unwrap();
+    let response = app
+        .request(Method::POST, "/api/v1/categories", Some(&admin), Some(json!({ "name": "Books" })))
+        .await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+
+    // 삭제된 사용자의 token은 거부된다.
+    users::Entity::delete_by_id(alice_id as i32).exec(&app.state.conn).await.unwrap();
+    assert_eq!(app.get("/api/v1/products", Some(&alice)).await.status, StatusCode::UNAUTHORIZED);
+}
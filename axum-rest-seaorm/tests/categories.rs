@@ -0,0 +1,424 @@
+mod common;
+
+use axum::http::{header, HeaderName, Method, StatusCode};
+use common::TestApp;
+use serde_json::json;
+
+#[tokio::test]
+async fn create_category_requires_admin() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+
+    let response = app
+        .request(Method::POST, "/api/v1/categories", Some(&token), Some(json!({ "name": "Books" })))
+        .await;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn create_get_and_list_categories() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    let created = app
+        .request(Method::POST, "/api/v1/categories", Some(&admin), Some(json!({ "name": "Books" })))
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED);
+    assert_eq!(created.header(header::LOCATION), Some("/api/v1/categories/Books"));
+
+    let found = app.get("/api/v1/categories/Books", Some(&admin)).await;
+    assert_eq!(found.status, StatusCode::OK);
+    assert_eq!(found.body["name"], "Books");
+
+    let list = app.get("/api/v1/categories", Some(&admin)).await;
+    assert_eq!(list.status, StatusCode::OK);
+    assert_eq!(list.body["total"], 1);
+}
+
+#[tokio::test]
+async fn create_category_rejects_duplicate_and_blank_name() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let body = json!({ "name": "Books" });
+
+    app.request(Method::POST, "/api/v1/categories", Some(&admin), Some(body.clone())).await;
+    let duplicate = app
+        .request(Method::POST, "/api/v1/categories", Some(&admin), Some(body))
+        .await;
+    assert_eq!(duplicate.status, StatusCode::CONFLICT);
+
+    let blank = app
+        .request(Method::POST, "/api/v1/categories", Some(&admin), Some(json!({ "name": "  " })))
+        .await;
+    assert_eq!(blank.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn delete_category() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.request(Method::POST, "/api/v1/categories", Some(&admin), Some(json!({ "name": "Books" })))
+        .await;
+
+    let deleted = app
+        .request(Method::DELETE, "/api/v1/categories/Books", Some(&admin), None)
+        .await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+
+    let missing = app.get("/api/v1/categories/Books", Some(&admin)).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn delete_category_in_use_lists_dependents() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+    let novel = app.create_product(&admin, "Novel", 20, "Books").await;
+
+    let response = app
+        .request(Method::DELETE, "/api/v1/categories/Books", Some(&admin), None)
+        .await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["code"], "in_use");
+    assert_eq!(response.body["dependents"][0]["entity"], "product");
+    assert_eq!(response.body["dependents"][0]["id"], novel["id"].to_string());
+    assert_eq!(response.body["dependents"][0]["name"], "Novel");
+    assert_eq!(app.get("/api/v1/categories/Books", Some(&admin)).await.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn delete_category_reassigns_products() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+    app.create_category(&admin, "Media").await;
+    let novel = app.create_product(&admin, "Novel", 20, "Books").await;
+
+    let same = app
+        .request(Method::DELETE, "/api/v1/categories/Books?reassign_to=Books", Some(&admin), None)
+        .await;
+    assert_eq!(same.status, StatusCode::BAD_REQUEST);
+    let missing = app
+        .request(Method::DELETE, "/api/v1/categories/Books?reassign_to=Nope", Some(&admin), None)
+        .await;
+    assert_eq!(missing.status, StatusCode::BAD_REQUEST);
+
+    let response = app
+        .request(Method::DELETE, "/api/v1/categories/Books?reassign_to=Media", Some(&admin), None)
+        .await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+
+    let product = app.get(&format!("/api/v1/products/{}", novel["id"]), Some(&admin)).await;
+    assert_eq!(product.body["category"], "Media");
+    assert_eq!(app.get("/api/v1/categories/Books", Some(&admin)).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn legacy_routes_are_marked_deprecated() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+
+    let response = app.get("/categories?name=Books", Some(&admin)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["items"][0]["name"], "Books");
+    assert_eq!(response.header(HeaderName::from_static("deprecation")), Some("true"));
+    let links: Vec<&str> = response
+        .headers
+        .get_all(header::LINK)
+        .iter()
+        .map(|value| value.to_str().unwrap())
+        .collect();
+    assert!(links.contains(&"</api/v1>; rel=\"successor-version\""), "{:?}", links);
+
+    // 에러 응답에도 붙는다.
+    let response = app.get("/categories", None).await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(response.header(HeaderName::from_static("deprecation")), Some("true"));
+
+    let response = app.get("/api/v1/categories", Some(&admin)).await;
+    assert!(response.header(HeaderName::from_static("deprecation")).is_none());
+}
+
+#[tokio::test]
+async fn legacy_delete_category_matches_exact_name() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+    app.create_category(&admin, "Toys").await;
+    let novel = app.create_product(&admin, "Novel", 20, "Books").await;
+
+    let missing = app.request(Method::DELETE, "/categories", Some(&admin), None).await;
+    assert_eq!(missing.status, StatusCode::BAD_REQUEST);
+
+    // 부분 일치로는 삭제하지 않는다.
+    let partial = app.request(Method::DELETE, "/categories?name=o", Some(&admin), None).await;
+    assert_eq!(partial.status, StatusCode::NOT_FOUND);
+
+    let in_use = app.request(Method::DELETE, "/categories?name=Books", Some(&admin), None).await;
+    assert_eq!(in_use.status, StatusCode::CONFLICT);
+    assert_eq!(in_use.body["code"], "in_use");
+    assert_eq!(in_use.body["dependents"][0]["id"], novel["id"].to_string());
+
+    let deleted = app
+        .request(Method::DELETE, "/categories?name=Books&reassign_to=Toys", Some(&admin), None)
+        .await;
+    assert_eq!(deleted.status, StatusCode::OK);
+    assert_eq!(app.get("/api/v1/categories/Books", Some(&admin)).await.status, StatusCode::NOT_FOUND);
+    let product = app.get(&format!("/api/v1/products/{}", novel["id"]), Some(&admin)).await;
+    assert_eq!(product.body["category"], "Toys");
+}
+
+#[tokio::test]
+async fn rename_category_cascades_to_products() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let created = app.create_category(&admin, "Books").await;
+    let novel = app.create_product(&admin, "Novel", 20, "Books").await;
+
+    let response = app
+        .request(
+            Method::PATCH,
+            "/api/v1/categories/Books",
+            Some(&admin),
+            Some(json!({ "name": "Literature" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["name"], "Literature");
+    assert_eq!(response.body["id"], created["id"]);
+    let product = app.get(&format!("/api/v1/products/{}", novel["id"]), Some(&admin)).await;
+    assert_eq!(product.body["category"], "Literature");
+    assert_eq!(app.get("/api/v1/categories/Books", Some(&admin)).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rename_category_rejects_existing_name() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    app.create_category(&admin, "Books").await;
+    app.create_category(&admin, "Media").await;
+    let body = json!({ "name": "Media" });
+
+    let forbidden = app
+        .request(Method::PATCH, "/api/v1/categories/Books", Some(&token), Some(body.clone()))
+        .await;
+    assert_eq!(forbidden.status, StatusCode::FORBIDDEN);
+
+    let conflict = app
+        .request(Method::PATCH, "/api/v1/categories/Books", Some(&admin), Some(body.clone()))
+        .await;
+    assert_eq!(conflict.status, StatusCode::CONFLICT);
+
+    let missing = app
+        .request(Method::PATCH, "/api/v1/categories/Nope", Some(&admin), Some(json!({ "name": "New" })))
+        .await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn list_categories_with_product_counts() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+    app.create_category(&admin, "Empty").await;
+    app.create_product(&admin, "Novel", 20, "Books").await;
+    let deleted = app.create_product(&admin, "Comic", 10, "Books").await;
+    app.request(
+        Method::DELETE,
+        &format!("/api/v1/products/{}", deleted["id"]),
+        Some(&admin),
+        None,
+    )
+    .await;
+
+    let without = app.get("/api/v1/categories?sort=name", Some(&admin)).await;
+    assert!(without.body["items"][0].get("product_count").is_none());
+
+    let with = app
+        .get("/api/v1/categories?sort=name&include_counts=true", Some(&admin))
+        .await;
+    assert_eq!(with.status, StatusCode::OK);
+    // soft delete된 product는 집계하지 않는다.
+    assert_eq!(with.body["items"][0]["name"], "Books");
+    assert_eq!(with.body["items"][0]["product_count"], 1);
+    assert_eq!(with.body["items"][1]["name"], "Empty");
+    assert_eq!(with.body["items"][1]["product_count"], 0);
+}
+
+// Electronics -> Laptops -> Gaming, Books
+async fn create_tree(app: &TestApp, token: &str) {
+    app.create_category(token, "Electronics").await;
+    app.create_category(token, "Books").await;
+    for (name, parent) in [("Laptops", "Electronics"), ("Gaming", "Laptops")] {
+        let response = app
+            .request(
+                Method::POST,
+                "/api/v1/categories",
+                Some(token),
+                Some(json!({ "name": name, "parent": parent })),
+            )
+            .await;
+        assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    }
+}
+
+#[tokio::test]
+async fn create_category_with_unknown_parent_conflicts() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/categories",
+            Some(&admin),
+            Some(json!({ "name": "Laptops", "parent": "Missing" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn category_tree_and_subtree() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    create_tree(&app, &admin).await;
+
+    let tree = app.get("/api/v1/categories/tree", Some(&admin)).await;
+    assert_eq!(tree.status, StatusCode::OK);
+    assert_eq!(tree.body[0]["name"], "Books");
+    assert_eq!(tree.body[0]["children"], json!([]));
+    assert_eq!(tree.body[1]["name"], "Electronics");
+    assert_eq!(tree.body[1]["children"][0]["name"], "Laptops");
+    assert_eq!(tree.body[1]["children"][0]["children"][0]["name"], "Gaming");
+
+    let subtree = app.get("/api/v1/categories/Laptops/tree", Some(&admin)).await;
+    assert_eq!(subtree.status, StatusCode::OK);
+    assert_eq!(subtree.body["name"], "Laptops");
+    assert_eq!(subtree.body["children"][0]["name"], "Gaming");
+
+    let missing = app.get("/api/v1/categories/Missing/tree", Some(&admin)).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn move_category() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    create_tree(&app, &admin).await;
+    let books = app.get("/api/v1/categories/Books", Some(&admin)).await;
+
+    let moved = app
+        .request(
+            Method::POST,
+            "/api/v1/categories/Gaming/move",
+            Some(&admin),
+            Some(json!({ "parent": "Books" })),
+        )
+        .await;
+    assert_eq!(moved.status, StatusCode::OK);
+    assert_eq!(moved.body["parent_id"], books.body["id"]);
+
+    let root = app
+        .request(Method::POST, "/api/v1/categories/Laptops/move", Some(&admin), Some(json!({})))
+        .await;
+    assert_eq!(root.status, StatusCode::OK);
+    assert!(root.body["parent_id"].is_null());
+
+    let tree = app.get("/api/v1/categories/tree", Some(&admin)).await;
+    let roots: Vec<&str> = tree.body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(roots, ["Books", "Electronics", "Laptops"]);
+    assert_eq!(tree.body[0]["children"][0]["name"], "Gaming");
+}
+
+#[tokio::test]
+async fn move_category_prevents_cycles() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    create_tree(&app, &admin).await;
+
+    for parent in ["Electronics", "Laptops", "Gaming"] {
+        let response = app
+            .request(
+                Method::POST,
+                "/api/v1/categories/Electronics/move",
+                Some(&admin),
+                Some(json!({ "parent": parent })),
+            )
+            .await;
+        assert_eq!(response.status, StatusCode::CONFLICT, "parent {}", parent);
+    }
+
+    let (_, token) = app.user("alice").await;
+    let forbidden = app
+        .request(
+            Method::POST,
+            "/api/v1/categories/Gaming/move",
+            Some(&token),
+            Some(json!({ "parent": "Books" })),
+        )
+        .await;
+    assert_eq!(forbidden.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn delete_category_with_children_conflicts() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    create_tree(&app, &admin).await;
+
+    let response = app
+        .request(Method::DELETE, "/api/v1/categories/Electronics", Some(&admin), None)
+        .await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["code"], "in_use");
+    assert_eq!(response.body["dependents"][0]["entity"], "category");
+    assert_eq!(response.body["dependents"][0]["name"], "Laptops");
+}
+
+#[tokio::test]
+async fn list_products_including_descendant_categories() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    create_tree(&app, &admin).await;
+    app.create_product(&admin, "Monitor", 300, "Electronics").await;
+    app.create_product(&admin, "Ultrabook", 1200, "Laptops").await;
+    app.create_product(&admin, "Gaming Laptop", 2500, "Gaming").await;
+    app.create_product(&admin, "Novel", 20, "Books").await;
+
+    let titles = |body: &serde_json::Value| -> Vec<String> {
+        body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|product| product["title"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let direct = app.get("/api/v1/products?category=Electronics", Some(&admin)).await;
+    assert_eq!(titles(&direct.body), ["Monitor"]);
+
+    let all = app
+        .get("/api/v1/products?category=Electronics&include_descendants=true", Some(&admin))
+        .await;
+    assert_eq!(all.status, StatusCode::OK);
+    assert_eq!(titles(&all.body), ["Monitor", "Ultrabook", "Gaming Laptop"]);
+
+    let laptops = app
+        .get("/api/v1/products?category=Laptops&include_descendants=true&price_gte=2000", Some(&admin))
+        .await;
+    assert_eq!(titles(&laptops.body), ["Gaming Laptop"]);
+}
@@ -0,0 +1,258 @@
+// integration test 공통 helper
+// -- 테스트마다 별도의 in-memory SQLite DB에 migration을 적용하고 build_app으로 만든 router를
+//    tower::ServiceExt::oneshot으로 직접 호출한다. (네트워크 포트 사용 안 함)
+#![allow(dead_code)]
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, HeaderMap, Method, Request, StatusCode},
+    Router,
+};
+use axum_rest_seaorm::{
+    build_app,
+    config::Config,
+    db::{
+        init_db,
+        seed::{self, Fixtures, UserFixture},
+    },
+    entities::inventory,
+    state::AppState,
+    utils::hash::PasswordHasher,
+};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{sea_query::Expr, ColumnTrait, EntityTrait, QueryFilter};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+pub const ADMIN_USERNAME: &str = "admin";
+pub const ADMIN_PASSWORD: &str = "admin-password1";
+
+pub struct TestApp {
+    pub router: Router,
+    pub state: AppState,
+}
+
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Value,
+    // 이미지 등 JSON이 아닌 응답 본문
+    pub bytes: Vec<u8>,
+}
+
+impl TestResponse {
+    pub fn header(&self, name: header::HeaderName) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+impl TestApp {
+    pub async fn new() -> Self {
+        Self::with_config(|_| {}).await
+    }
+
+    // DATABASE_URL의 Postgres DB로 실행 (Postgres 전용 기능 테스트, #[ignore]와 함께 사용)
+    // -- 기존 table을 모두 지우므로 테스트 전용 DB를 지정해야 하며 --test-threads=1로 실행한다.
+    pub async fn postgres() -> Self {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must point to a test Postgres DB");
+        Self::with_config(|config| config.database.url = url).await
+    }
+
+    // 기본 테스트 설정을 바꿔서 실행 (업로드 크기 제한 등)
+    pub async fn with_config(configure: impl FnOnce(&mut Config)) -> Self {
+        let mut config = Config::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.auth.jwt_secret = "integration-test-jwt-secret".to_string();
+        // 테스트 속도를 위해 최소 cost 사용
+        config.auth.bcrypt_cost = 4;
+        // 업로드 파일은 테스트마다 별도의 임시 디렉터리에 저장 (drop할 때 삭제)
+        config.storage.local_root =
+            std::env::temp_dir().join(format!("axum-rest-seaorm-test-{}", uuid::Uuid::new_v4()));
+        configure(&mut config);
+
+        let conn = init_db(&config.database).await.expect("failed to open test database");
+        // Postgres는 테스트 간에 DB를 공유하므로 매번 비운 뒤 migration을 다시 적용한다.
+        if config.database.url.starts_with("postgres") {
+            Migrator::fresh(&conn).await.expect("failed to apply migrations");
+        } else {
+            Migrator::up(&conn, None).await.expect("failed to apply migrations");
+        }
+
+        // signup은 항상 user 권한으로 가입하므로 admin 계정은 seed로 만든다.
+        let fixtures = Fixtures {
+            users: vec![UserFixture {
+                username: ADMIN_USERNAME.to_string(),
+                role: Some("admin".to_string()),
+                password: Some(ADMIN_PASSWORD.to_string()),
+                password_env: None,
+            }],
+            ..Default::default()
+        };
+        seed::seed(&conn, &PasswordHasher::new(&config.auth), fixtures)
+            .await
+            .expect("failed to seed admin user");
+
+        let state = AppState::new(conn, config);
+        Self {
+            router: build_app(state.clone()),
+            state,
+        }
+    }
+
+    pub async fn request(
+        &self,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+        body: Option<Value>,
+    ) -> TestResponse {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let request = match body {
+            Some(body) => builder
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .unwrap();
+
+        self.send(request).await
+    }
+
+    // JSON이 아닌 본문 (multipart 등)으로 요청
+    pub async fn request_raw(
+        &self,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+        content_type: &str,
+        body: impl Into<Body>,
+    ) -> TestResponse {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, content_type);
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+
+        self.send(builder.body(body.into()).unwrap()).await
+    }
+
+    // 본문 없이 추가 헤더 (Range, If-None-Match 등)와 함께 요청
+    pub async fn request_with_headers(
+        &self,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+        headers: &[(header::HeaderName, &str)],
+    ) -> TestResponse {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        for (name, value) in headers {
+            builder = builder.header(name, *value);
+        }
+
+        self.send(builder.body(Body::empty()).unwrap()).await
+    }
+
+    async fn send(&self, request: Request<Body>) -> TestResponse {
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes)
+                .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()))
+        };
+
+        TestResponse { status, headers, body, bytes: bytes.to_vec() }
+    }
+
+    pub async fn get(&self, uri: &str, token: Option<&str>) -> TestResponse {
+        self.request(Method::GET, uri, token, None).await
+    }
+
+    pub async fn signup(&self, username: &str, password: &str) -> TestResponse {
+        self.request(
+            Method::POST,
+            "/api/v1/auth/signup",
+            None,
+            Some(json!({ "username": username, "password": password })),
+        )
+        .await
+    }
+
+    pub async fn login(&self, username: &str, password: &str) -> TestResponse {
+        self.request(
+            Method::POST,
+            "/api/v1/auth/login",
+            None,
+            Some(json!({ "username": username, "password": password })),
+        )
+        .await
+    }
+
+    // 로그인 후 access token 반환
+    pub async fn token(&self, username: &str, password: &str) -> String {
+        let response = self.login(username, password).await;
+        assert_eq!(response.status, StatusCode::OK, "login failed: {}", response.body);
+        response.body["access_token"].as_str().unwrap().to_string()
+    }
+
+    pub async fn admin_token(&self) -> String {
+        self.token(ADMIN_USERNAME, ADMIN_PASSWORD).await
+    }
+
+    pub async fn create_category(&self, token: &str, name: &str) -> Value {
+        let response = self
+            .request(Method::POST, "/api/v1/categories", Some(token), Some(json!({ "name": name })))
+            .await;
+        assert_eq!(response.status, StatusCode::CREATED, "create category failed: {}", response.body);
+        response.body
+    }
+
+    pub async fn create_product(&self, token: &str, title: &str, price: i32, category: &str) -> Value {
+        let response = self
+            .request(
+                Method::POST,
+                "/api/v1/products",
+                Some(token),
+                Some(json!({ "title": title, "price": price, "category": category })),
+            )
+            .await;
+        assert_eq!(response.status, StatusCode::CREATED, "create product failed: {}", response.body);
+        response.body
+    }
+
+    // API로는 만들기 어려운 재고 수량을 직접 설정 (overflow 테스트용, inventory row가 있어야 함)
+    pub async fn set_stock_quantity(&self, product_id: i64, quantity: i32) {
+        inventory::Entity::update_many()
+            .col_expr(inventory::Column::Quantity, Expr::value(quantity))
+            .filter(inventory::Column::ProductId.eq(product_id as i32))
+            .exec(&self.state.conn)
+            .await
+            .unwrap();
+    }
+
+    // 일반 사용자를 가입시키고 (user id, access token) 반환
+    pub async fn user(&self, username: &str) -> (i64, String) {
+        let password = "user-password1";
+        let response = self.signup(username, password).await;
+        assert_eq!(response.status, StatusCode::CREATED, "signup failed: {}", response.body);
+        let id = response.body["id"].as_i64().unwrap();
+        (id, self.token(username, password).await)
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.state.config.storage.local_root);
+    }
+}
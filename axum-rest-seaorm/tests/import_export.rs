@@ -0,0 +1,389 @@
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use common::{TestApp, TestResponse};
+use serde_json::Value;
+
+const BOUNDARY: &str = "test-boundary";
+
+// 파일 하나를 'file' 필드로 담은 multipart 본문
+fn multipart(file_name: &str, content: &str) -> String {
+    format!(
+        "--{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{f}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n{c}\r\n--{b}--\r\n",
+        b = BOUNDARY,
+        f = file_name,
+        c = content
+    )
+}
+
+async fn import(app: &TestApp, token: &str, query: &str, file_name: &str, content: &str) -> TestResponse {
+    app.request_raw(
+        Method::POST,
+        &format!("/api/v1/products/import{}", query),
+        Some(token),
+        &format!("multipart/form-data; boundary={}", BOUNDARY),
+        multipart(file_name, content),
+    )
+    .await
+}
+
+async fn import_categories(app: &TestApp, token: &str, query: &str, file_name: &str, content: &str) -> TestResponse {
+    app.request_raw(
+        Method::POST,
+        &format!("/api/v1/categories/import{}", query),
+        Some(token),
+        &format!("multipart/form-data; boundary={}", BOUNDARY),
+        multipart(file_name, content),
+    )
+    .await
+}
+
+async fn product_titles(app: &TestApp, token: &str) -> Vec<String> {
+    let response = app.get("/api/v1/products?sort=id", Some(token)).await;
+    response.body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|product| product["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn import_csv_creates_products_and_categories() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+
+    let csv = "\u{feff}title,price,currency,category\r\n\
+               Laptop,1200,,Electronics\r\n\
+               \"Desk, oak\",\"99.50\",USD,Furniture\r\n\
+               \"Multi\nline\",10,KRW,Furniture\r\n";
+    let response = import(&app, &admin, "", "products.csv", csv).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["rows"], 3);
+    assert_eq!(response.body["imported"], 3);
+    assert_eq!(response.body["failed"], 0);
+    assert_eq!(response.body["committed"], true);
+    assert_eq!(response.body["created_categories"], serde_json::json!(["Furniture"]));
+
+    assert_eq!(product_titles(&app, &admin).await, ["Laptop", "Desk, oak", "Multi\nline"]);
+    let category = app.get("/api/v1/categories/Furniture", Some(&admin)).await;
+    assert_eq!(category.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn import_all_or_nothing_rolls_back_on_row_errors() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    let csv = "title,price,category\n\
+               Laptop,1200,Electronics\n\
+               ,10,Electronics\n\
+               Mouse,-1,Electronics\n\
+               Cable,1.5,Electronics\n";
+    let response = import(&app, &admin, "?format=csv", "upload.bin", csv).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", response.body);
+    assert_eq!(response.body["committed"], false);
+    assert_eq!(response.body["imported"], 0);
+    assert_eq!(response.body["failed"], 3);
+    let errors = response.body["errors"].as_array().unwrap();
+    assert_eq!(errors[0]["line"], 3);
+    assert_eq!(errors[0]["errors"][0]["field"], "title");
+    assert_eq!(errors[1]["line"], 4);
+    assert_eq!(errors[1]["errors"][0]["field"], "price");
+    // KRW는 소수점 이하 금액을 허용하지 않는다.
+    assert_eq!(errors[2]["errors"][0]["field"], "price");
+
+    // category 자동 생성까지 모두 취소
+    assert!(product_titles(&app, &admin).await.is_empty());
+    let category = app.get("/api/v1/categories/Electronics", Some(&admin)).await;
+    assert_eq!(category.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn import_best_effort_keeps_valid_ndjson_rows() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+
+    let ndjson = concat!(
+        r#"{"title":"Laptop","price":"1200","category":"Electronics"}"#, "\n",
+        r#"{"title":"Desk","price":100,"category":"Furniture"}"#, "\n",
+        "not json\n",
+        "\n",
+        r#"{"title":"Mouse","price":30,"category":"Electronics","currency":"usd"}"#, "\n",
+        r#"{"title":"Cable","price":5,"category":"Electronics","id":77}"#,
+    );
+    let response = import(
+        &app,
+        &admin,
+        "?mode=best_effort&create_categories=false",
+        "products.ndjson",
+        ndjson,
+    )
+    .await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["rows"], 5);
+    assert_eq!(response.body["imported"], 2);
+    assert_eq!(response.body["failed"], 3);
+    assert_eq!(response.body["committed"], true);
+
+    let errors = response.body["errors"].as_array().unwrap();
+    assert_eq!(errors[0]["line"], 2);
+    assert_eq!(errors[0]["errors"][0]["field"], "category");
+    assert_eq!(errors[0]["errors"][0]["message"], "category does not exist");
+    assert_eq!(errors[1]["line"], 3);
+    assert_eq!(errors[2]["line"], 5);
+    assert_eq!(errors[2]["errors"][0]["field"], "currency");
+
+    assert_eq!(product_titles(&app, &admin).await, ["Laptop", "Cable"]);
+}
+
+#[tokio::test]
+async fn import_rejects_bad_requests() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+
+    let csv = "title,price,category\nLaptop,1200,Electronics\n";
+    assert_eq!(import(&app, &token, "", "products.csv", csv).await.status, StatusCode::FORBIDDEN);
+
+    // 형식을 알 수 없는 파일
+    let response = import(&app, &admin, "", "products.txt", csv).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    let response = import(&app, &admin, "?format=xml", "products.csv", csv).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+
+    // 필수 컬럼이 없는 header
+    let response = import(&app, &admin, "", "products.csv", "title,price\nLaptop,1200\n").await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(response.body["detail"], "CSV header is missing required columns: category");
+
+    // file 필드가 없는 multipart
+    let response = app
+        .request_raw(
+            Method::POST,
+            "/api/v1/products/import",
+            Some(&admin),
+            &format!("multipart/form-data; boundary={}", BOUNDARY),
+            format!("--{b}\r\nContent-Disposition: form-data; name=\"other\"\r\n\r\nx\r\n--{b}--\r\n", b = BOUNDARY),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn import_is_not_limited_by_request_timeout() {
+    // 일반 요청의 timeout은 짧게 두고 import에는 import_timeout_ms를 적용
+    let app = TestApp::with_config(|config| {
+        config.server.request_timeout_ms = 50;
+        config.server.import_timeout_ms = 60_000;
+    })
+    .await;
+    let admin = app.admin_token().await;
+
+    let mut csv = String::from("title,price,category\n");
+    for i in 0..3000 {
+        csv.push_str(&format!("Product {},{},Category {}\n", i, i + 1, i % 50));
+    }
+    let started = std::time::Instant::now();
+    let response = import(&app, &admin, "", "products.csv", &csv).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["imported"], 3000);
+    // request_timeout_ms보다 오래 걸리는 import
+    assert!(started.elapsed().as_millis() > 50);
+}
+
+#[tokio::test]
+async fn import_rejects_oversized_upload() {
+    let app = TestApp::with_config(|config| config.server.max_upload_bytes = 1024).await;
+    let admin = app.admin_token().await;
+
+    let mut csv = String::from("title,price,category\n");
+    for i in 0..100 {
+        csv.push_str(&format!("Product {},100,Electronics\n", i));
+    }
+    let response = import(&app, &admin, "", "products.csv", &csv).await;
+    assert_eq!(response.status, StatusCode::PAYLOAD_TOO_LARGE, "{}", response.body);
+    assert_eq!(response.body["code"], "payload_too_large");
+    assert!(product_titles(&app, &admin).await.is_empty());
+}
+
+#[tokio::test]
+async fn export_escapes_spreadsheet_formulas() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "@Tools").await;
+    app.create_product(&admin, "=HYPERLINK(\"http://evil\")", 10, "@Tools").await;
+    app.create_product(&admin, "-5 Cable", 20, "@Tools").await;
+    app.create_product(&admin, "'=quoted", 30, "@Tools").await;
+    app.create_product(&admin, "'plain", 40, "@Tools").await;
+
+    let response = app.get("/api/v1/products/export", Some(&admin)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    let csv = response.body.as_str().unwrap().to_string();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert!(lines[1].starts_with("1,\"'=HYPERLINK(\"\"http://evil\"\")\",10,KRW,'@Tools,"), "{}", lines[1]);
+    assert!(lines[2].starts_with("2,'-5 Cable,20,KRW,'@Tools,"), "{}", lines[2]);
+    assert!(lines[3].starts_with("3,''=quoted,"), "{}", lines[3]);
+    assert!(lines[4].starts_with("4,'plain,"), "{}", lines[4]);
+
+    // import하면 export에서 붙인 '가 제거되어 원래 값으로 돌아온다.
+    let response = import(&app, &admin, "", "products.csv", &csv).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["created_categories"], serde_json::json!([]));
+    assert_eq!(
+        product_titles(&app, &admin).await[4..],
+        ["=HYPERLINK(\"http://evil\")", "-5 Cable", "'=quoted", "'plain"]
+    );
+}
+
+#[tokio::test]
+async fn export_streams_csv_and_ndjson() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    app.create_category(&admin, "Electronics").await;
+    app.create_category(&admin, "Furniture").await;
+    app.create_product(&admin, "Laptop, 14\"", 1200, "Electronics").await;
+    app.create_product(&admin, "Desk", 300, "Furniture").await;
+    let deleted = app.create_product(&admin, "Old", 1, "Electronics").await["id"].as_i64().unwrap();
+    app.request(Method::DELETE, &format!("/api/v1/products/{}", deleted), Some(&admin), None)
+        .await;
+
+    let response = app.get("/api/v1/products/export", Some(&token)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.header(header::CONTENT_TYPE).unwrap().starts_with("text/csv"));
+    assert!(response.header(header::CONTENT_DISPOSITION).unwrap().contains("products.csv"));
+    let csv = response.body.as_str().unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "id,title,price,currency,category,created_at,updated_at");
+    assert!(lines[1].starts_with("1,\"Laptop, 14\"\"\",1200,KRW,Electronics,"));
+    assert!(lines[2].starts_with("2,Desk,300,KRW,Furniture,"));
+
+    // export한 CSV는 그대로 다시 import할 수 있다. (id 등 모르는 컬럼은 무시)
+    let response = import(&app, &admin, "", "products.csv", csv).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["imported"], 2);
+
+    let response = app
+        .get("/api/v1/products/export?format=ndjson&category=Furniture", Some(&token))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.header(header::CONTENT_TYPE), Some("application/x-ndjson"));
+    let rows: Vec<Value> = response
+        .body
+        .as_str()
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|row| row["category"] == "Furniture"));
+    assert_eq!(rows[0]["price"], "300");
+
+    let response = app.get("/api/v1/products/export?format=xml", Some(&token)).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(app.get("/api/v1/products/export", None).await.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn category_export_lists_parents_first_and_imports_back() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    app.create_category(&admin, "Gaming").await;
+    app.create_category(&admin, "Electronics").await;
+    app.create_category(&admin, "=Sale").await;
+    // 상위 category(Electronics)가 나중에 만들어진 경우
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/categories/Gaming/move",
+            Some(&admin),
+            Some(serde_json::json!({ "parent": "Electronics" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+    let response = app.get("/api/v1/categories/export", Some(&token)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.header(header::CONTENT_DISPOSITION).unwrap().contains("categories.csv"));
+    let csv = response.body.as_str().unwrap().to_string();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "id,name,parent,created_at,updated_at");
+    assert!(lines[1].starts_with("2,Electronics,,"), "{}", lines[1]);
+    assert!(lines[2].starts_with("3,'=Sale,,"), "{}", lines[2]);
+    assert!(lines[3].starts_with("1,Gaming,Electronics,"), "{}", lines[3]);
+
+    let response = app.get("/api/v1/categories/export?format=ndjson", Some(&token)).await;
+    assert_eq!(response.header(header::CONTENT_TYPE), Some("application/x-ndjson"));
+    let rows: Vec<Value> = response
+        .body
+        .as_str()
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(rows[2]["name"], "Gaming");
+    assert_eq!(rows[2]["parent"], "Electronics");
+    assert_eq!(rows[1]["name"], "=Sale");
+
+    // 다른 서버에 그대로 import
+    let other = TestApp::new().await;
+    let other_admin = other.admin_token().await;
+    let response = import_categories(&other, &other_admin, "", "categories.csv", &csv).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["imported"], 3);
+    let tree = other.get("/api/v1/categories/tree", Some(&other_admin)).await;
+    assert_eq!(tree.body[0]["name"], "=Sale");
+    assert_eq!(tree.body[1]["name"], "Electronics");
+    assert_eq!(tree.body[1]["children"][0]["name"], "Gaming");
+    let audit = other.get("/api/v1/audit?entity=category", Some(&other_admin)).await;
+    assert_eq!(audit.body["total"], 3);
+
+    let response = app.get("/api/v1/categories/export?format=xml", Some(&token)).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(app.get("/api/v1/categories/export", None).await.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn category_import_reports_row_errors() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    app.create_category(&admin, "Electronics").await;
+
+    let ndjson = "{\"name\": \"Laptops\", \"parent\": \"Electronics\"}\n\
+                  {\"name\": \"Electronics\"}\n\
+                  {\"name\": \"Chairs\", \"parent\": \"Furniture\"}\n\
+                  {\"parent\": \"Electronics\"}\n";
+    let response = import_categories(&app, &admin, "", "categories.ndjson", ndjson).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", response.body);
+    assert_eq!(response.body["committed"], false);
+    assert_eq!(response.body["failed"], 3);
+    let errors = response.body["errors"].as_array().unwrap();
+    assert_eq!(errors[0]["errors"][0]["field"], "name");
+    assert_eq!(errors[0]["errors"][0]["message"], "category already exists");
+    assert_eq!(errors[1]["errors"][0]["field"], "parent");
+    assert_eq!(errors[2]["errors"][0]["field"], "name");
+    let laptops = app.get("/api/v1/categories/Laptops", Some(&admin)).await;
+    assert_eq!(laptops.status, StatusCode::NOT_FOUND);
+
+    // best_effort는 통과한 행만 저장
+    let response = import_categories(&app, &admin, "?mode=best_effort", "categories.ndjson", ndjson).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["imported"], 1);
+    let laptops = app.get("/api/v1/categories/Laptops", Some(&admin)).await;
+    assert_eq!(laptops.status, StatusCode::OK);
+
+    let csv = "title\nLaptops\n";
+    let response = import_categories(&app, &admin, "", "categories.csv", csv).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+
+    let response = import_categories(&app, &token, "", "categories.csv", "name\nBooks\n").await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use std::time::Duration;
+
+use axum::http::{header, Method, StatusCode};
+use axum_rest_seaorm::api::reservation::expire_reservations;
+use common::TestApp;
+use serde_json::{json, Value};
+
+// Electronics 카테고리에 product를 만들고 id 반환
+async fn product(app: &TestApp, admin: &str) -> i64 {
+    app.create_category(admin, "Electronics").await;
+    app.create_product(admin, "Laptop", 1200, "Electronics").await["id"].as_i64().unwrap()
+}
+
+async fn adjust(app: &TestApp, admin: &str, product_id: i64, delta: i32, reason: &str) -> common::TestResponse {
+    app.request(
+        Method::POST,
+        &format!("/api/v1/products/{}/inventory/adjustments", product_id),
+        Some(admin),
+        Some(json!({ "delta": delta, "reason": reason })),
+    )
+    .await
+}
+
+async fn reserve(app: &TestApp, token: &str, body: Value) -> common::TestResponse {
+    app.request(Method::POST, "/api/v1/reservations", Some(token), Some(body)).await
+}
+
+async fn inventory(app: &TestApp, token: &str, product_id: i64) -> Value {
+    let response = app
+        .get(&format!("/api/v1/products/{}/inventory", product_id), Some(token))
+        .await;
+    assert_eq!(response.status, StatusCode::OK, "get inventory failed: {}", response.body);
+    response.body
+}
+
+#[tokio::test]
+async fn adjust_stock_records_history() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+
+    // 재고를 한 번도 변경하지 않은 product는 0
+    let empty = inventory(&app, &admin, id).await;
+    assert_eq!(empty["quantity"], 0);
+    assert_eq!(empty["updated_at"], Value::Null);
+
+    let response = adjust(&app, &admin, id, 10, "restock").await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["adjustment"]["reason"], "restock");
+    assert_eq!(response.body["adjustment"]["quantity_after"], 10);
+    assert_eq!(response.body["inventory"]["available"], 10);
+
+    let response = adjust(&app, &admin, id, -3, "damaged").await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["inventory"]["quantity"], 7);
+
+    // 보유 수량보다 많이 줄일 수 없다.
+    let response = adjust(&app, &admin, id, -8, "lost").await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+
+    let response = adjust(&app, &admin, id, 0, "correction").await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let response = adjust(&app, &admin, id, 1, "stolen").await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let history = app
+        .get(&format!("/api/v1/products/{}/inventory/adjustments?sort=-id", id), Some(&admin))
+        .await;
+    assert_eq!(history.status, StatusCode::OK);
+    let items = history.body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["delta"], -3);
+    assert_eq!(items[1]["delta"], 10);
+}
+
+#[tokio::test]
+async fn adjust_stock_rejects_quantity_overflow() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+    adjust(&app, &admin, id, 10, "restock").await;
+    app.set_stock_quantity(id, i32::MAX - 5).await;
+
+    let response = adjust(&app, &admin, id, 10, "restock").await;
+    assert_eq!(response.status, StatusCode::CONFLICT, "{}", response.body);
+    assert_eq!(inventory(&app, &admin, id).await["quantity"], i32::MAX - 5);
+
+    let response = adjust(&app, &admin, id, 5, "restock").await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["inventory"]["quantity"], i32::MAX);
+}
+
+#[tokio::test]
+async fn adjust_stock_requires_admin() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+    let (_, token) = app.user("alice").await;
+
+    let response = adjust(&app, &token, id, 10, "restock").await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+
+    let response = adjust(&app, &admin, 9999, 10, "restock").await;
+    assert_eq!(response.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn reservation_holds_stock_until_confirmed() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+    let (_, token) = app.user("alice").await;
+    adjust(&app, &admin, id, 5, "restock").await;
+
+    let response = reserve(&app, &token, json!({ "product_id": id, "quantity": 3 })).await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["status"], "pending");
+    let location = response.header(header::LOCATION).unwrap().to_string();
+
+    let stock = inventory(&app, &token, id).await;
+    assert_eq!(stock["reserved"], 3);
+    assert_eq!(stock["available"], 2);
+
+    // 판매 가능 수량을 넘는 예약, 예약된 재고를 줄이는 조정은 거부
+    let response = reserve(&app, &token, json!({ "product_id": id, "quantity": 3 })).await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    let response = adjust(&app, &admin, id, -3, "correction").await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+
+    let response = app
+        .request(Method::POST, &format!("{}/confirm", location), Some(&token), None)
+        .await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["status"], "confirmed");
+
+    let stock = inventory(&app, &token, id).await;
+    assert_eq!(stock["quantity"], 2);
+    assert_eq!(stock["reserved"], 0);
+
+    // 확정된 예약은 다시 확정하거나 취소할 수 없다.
+    let response = app
+        .request(Method::POST, &format!("{}/confirm", location), Some(&token), None)
+        .await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    let response = app.request(Method::DELETE, &location, Some(&token), None).await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+
+    // 확정하면 판매 이력이 남는다.
+    let history = app
+        .get(&format!("/api/v1/products/{}/inventory/adjustments?sort=-id", id), Some(&admin))
+        .await;
+    assert_eq!(history.body["items"][0]["reason"], "sale");
+    assert_eq!(history.body["items"][0]["delta"], -3);
+}
+
+#[tokio::test]
+async fn cancel_reservation_releases_stock() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+    let (_, alice) = app.user("alice").await;
+    let (_, bob) = app.user("bob").await;
+    adjust(&app, &admin, id, 5, "restock").await;
+
+    let response = reserve(&app, &alice, json!({ "product_id": id, "quantity": 5 })).await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let location = response.header(header::LOCATION).unwrap().to_string();
+
+    // 다른 사용자의 예약은 조회하거나 취소할 수 없다.
+    assert_eq!(app.get(&location, Some(&bob)).await.status, StatusCode::FORBIDDEN);
+    let response = app.request(Method::DELETE, &location, Some(&bob), None).await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+
+    let response = app.request(Method::DELETE, &location, Some(&alice), None).await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.get(&location, Some(&alice)).await.body["status"], "cancelled");
+    assert_eq!(inventory(&app, &alice, id).await["available"], 5);
+}
+
+#[tokio::test]
+async fn expired_reservations_release_stock() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+    let (_, token) = app.user("alice").await;
+    adjust(&app, &admin, id, 5, "restock").await;
+
+    let response = reserve(&app, &token, json!({ "product_id": id, "quantity": 1, "ttl_secs": 999_999 })).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = reserve(&app, &token, json!({ "product_id": id, "quantity": 4, "ttl_secs": 1 })).await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let location = response.header(header::LOCATION).unwrap().to_string();
+    assert_eq!(inventory(&app, &token, id).await["available"], 1);
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    // background task가 실행하는 정리 작업
+    let expired = expire_reservations(&app.state.conn).await.unwrap();
+    assert_eq!(expired, 1);
+    assert_eq!(app.get(&location, Some(&token)).await.body["status"], "expired");
+
+    let stock = inventory(&app, &token, id).await;
+    assert_eq!(stock["reserved"], 0);
+    assert_eq!(stock["available"], 5);
+
+    let response = app
+        .request(Method::POST, &format!("{}/confirm", location), Some(&token), None)
+        .await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["detail"], "Reservation is expired");
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use axum::http::StatusCode;
+use common::TestApp;
+
+#[tokio::test]
+async fn openapi_document_is_served() {
+    let app = TestApp::new().await;
+
+    let response = app.get("/api-docs/openapi.json", None).await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    let paths = response.body["paths"].as_object().unwrap();
+    assert!(paths.contains_key("/api/v1/products"));
+    assert!(paths.contains_key("/api/v1/auth/login"));
+}
+
+#[tokio::test]
+async fn health_endpoints() {
+    let app = TestApp::new().await;
+
+    let healthz = app.get("/healthz", None).await;
+    let readyz = app.get("/readyz", None).await;
+
+    assert_eq!(healthz.status, StatusCode::OK);
+    assert_eq!(readyz.status, StatusCode::OK);
+    assert_eq!(readyz.body["database"], "ok");
+}
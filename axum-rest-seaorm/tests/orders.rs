@@ -0,0 +1,271 @@
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use common::{TestApp, TestResponse};
+use serde_json::{json, Value};
+
+// 재고가 있는 product를 만들고 id 반환
+async fn stocked_product(app: &TestApp, admin: &str, title: &str, price: i32, stock: i32) -> i64 {
+    let id = app.create_product(admin, title, price, "Electronics").await["id"].as_i64().unwrap();
+    let response = app
+        .request(
+            Method::POST,
+            &format!("/api/v1/products/{}/inventory/adjustments", id),
+            Some(admin),
+            Some(json!({ "delta": stock, "reason": "restock" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED, "restock failed: {}", response.body);
+    id
+}
+
+async fn checkout(app: &TestApp, token: &str, items: Value) -> TestResponse {
+    app.request(Method::POST, "/api/v1/orders", Some(token), Some(json!({ "items": items }))).await
+}
+
+async fn set_status(app: &TestApp, token: &str, id: i64, status: &str) -> TestResponse {
+    app.request(
+        Method::PATCH,
+        &format!("/api/v1/orders/{}", id),
+        Some(token),
+        Some(json!({ "status": status })),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn checkout_snapshots_title_and_price() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let laptop = stocked_product(&app, &admin, "Laptop", 1200, 5).await;
+    let mouse = stocked_product(&app, &admin, "Mouse", 30, 10).await;
+    let (user_id, token) = app.user("alice").await;
+
+    let response = checkout(
+        &app,
+        &token,
+        json!([{ "product_id": laptop, "quantity": 2 }, { "product_id": mouse, "quantity": 1 }]),
+    )
+    .await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["user_id"], user_id);
+    assert_eq!(response.body["status"], "pending");
+    assert_eq!(response.body["currency"], "KRW");
+    assert_eq!(response.body["total"], "2430");
+    assert_eq!(response.body["items"][0]["title"], "Laptop");
+    assert_eq!(response.body["items"][0]["line_total"], "2400");
+    let location = response.header(header::LOCATION).unwrap().to_string();
+
+    // 주문 이후 product가 바뀌어도 주문 내역은 그대로
+    let response = app
+        .request(
+            Method::PATCH,
+            &format!("/api/v1/products/{}", laptop),
+            Some(&admin),
+            Some(json!({ "title": "Laptop Pro", "price": 1500 })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+
+    let order = app.get(&location, Some(&token)).await;
+    assert_eq!(order.status, StatusCode::OK);
+    assert_eq!(order.body["items"][0]["title"], "Laptop");
+    assert_eq!(order.body["items"][0]["unit_price"], "1200");
+    assert_eq!(order.body["total"], "2430");
+
+    // 재고 차감
+    let stock = app.get(&format!("/api/v1/products/{}/inventory", laptop), Some(&token)).await;
+    assert_eq!(stock.body["quantity"], 3);
+}
+
+#[tokio::test]
+async fn checkout_rejects_invalid_items() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let laptop = stocked_product(&app, &admin, "Laptop", 1200, 1).await;
+    let (_, token) = app.user("alice").await;
+
+    let response = checkout(&app, &token, json!([])).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = checkout(&app, &token, json!([{ "product_id": laptop, "quantity": 0 }])).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.body["errors"][0]["field"], "items[0].quantity");
+
+    let response = checkout(
+        &app,
+        &token,
+        json!([{ "product_id": laptop, "quantity": 1 }, { "product_id": laptop, "quantity": 1 }]),
+    )
+    .await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = checkout(&app, &token, json!([{ "product_id": 9999, "quantity": 1 }])).await;
+    assert_eq!(response.status, StatusCode::NOT_FOUND);
+
+    let response = checkout(&app, &token, json!([{ "product_id": laptop, "quantity": 2 }])).await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+
+    // 실패한 주문은 남지 않는다.
+    let orders = app.get("/api/v1/orders", Some(&token)).await;
+    assert_eq!(orders.body["total"], 0);
+}
+
+#[tokio::test]
+async fn order_status_transitions_are_guarded() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let laptop = stocked_product(&app, &admin, "Laptop", 1200, 5).await;
+    let (_, token) = app.user("alice").await;
+
+    let response = checkout(&app, &token, json!([{ "product_id": laptop, "quantity": 1 }])).await;
+    let id = response.body["id"].as_i64().unwrap();
+
+    // 결제/배송 처리는 관리자만
+    assert_eq!(set_status(&app, &token, id, "paid").await.status, StatusCode::FORBIDDEN);
+    // pending에서 바로 배송할 수 없다.
+    assert_eq!(set_status(&app, &admin, id, "shipped").await.status, StatusCode::CONFLICT);
+
+    let response = set_status(&app, &admin, id, "paid").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["status"], "paid");
+    assert!(response.body["paid_at"].is_string());
+
+    // 결제 후에는 주문자가 취소할 수 없다.
+    assert_eq!(set_status(&app, &token, id, "cancelled").await.status, StatusCode::FORBIDDEN);
+
+    let response = set_status(&app, &admin, id, "shipped").await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.body["shipped_at"].is_string());
+
+    // 배송된 주문은 취소할 수 없다.
+    let response = set_status(&app, &admin, id, "cancelled").await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["detail"], "Cannot change order status from shipped to cancelled");
+
+    let response = set_status(&app, &admin, id, "refunded").await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn cancel_order_restores_stock() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let laptop = stocked_product(&app, &admin, "Laptop", 1200, 5).await;
+    let (_, token) = app.user("alice").await;
+
+    let response = checkout(&app, &token, json!([{ "product_id": laptop, "quantity": 3 }])).await;
+    let id = response.body["id"].as_i64().unwrap();
+
+    let response = set_status(&app, &token, id, "cancelled").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert!(response.body["cancelled_at"].is_string());
+
+    let stock = app.get(&format!("/api/v1/products/{}/inventory", laptop), Some(&token)).await;
+    assert_eq!(stock.body["quantity"], 5);
+
+    assert_eq!(set_status(&app, &token, id, "paid").await.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn checkout_rejects_total_out_of_range() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": "Mainframe", "price": "100000000000000", "category": "Electronics" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    let mainframe = response.body["id"].as_i64().unwrap();
+    app.request(
+        Method::POST,
+        &format!("/api/v1/products/{}/inventory/adjustments", mainframe),
+        Some(&admin),
+        Some(json!({ "delta": 20, "reason": "restock" })),
+    )
+    .await;
+    let (_, token) = app.user("alice").await;
+
+    // 합계 10^15는 numeric(19, 4)의 정수부(15자리)를 넘는다.
+    let response = checkout(&app, &token, json!([{ "product_id": mainframe, "quantity": 10 }])).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", response.body);
+    assert_eq!(response.body["errors"][0]["field"], "items");
+
+    let stock = app.get(&format!("/api/v1/products/{}/inventory", mainframe), Some(&token)).await;
+    assert_eq!(stock.body["quantity"], 20);
+
+    let response = checkout(&app, &token, json!([{ "product_id": mainframe, "quantity": 9 }])).await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["total"], "900000000000000");
+}
+
+#[tokio::test]
+async fn cancel_order_rejects_stock_overflow() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let laptop = stocked_product(&app, &admin, "Laptop", 1200, 5).await;
+    let (_, token) = app.user("alice").await;
+
+    let response = checkout(&app, &token, json!([{ "product_id": laptop, "quantity": 3 }])).await;
+    let id = response.body["id"].as_i64().unwrap();
+    app.set_stock_quantity(laptop, i32::MAX - 1).await;
+
+    // 재고를 되돌릴 수 없으면 취소도 rollback
+    let response = set_status(&app, &token, id, "cancelled").await;
+    assert_eq!(response.status, StatusCode::CONFLICT, "{}", response.body);
+    let order = app.get(&format!("/api/v1/orders/{}", id), Some(&token)).await;
+    assert_eq!(order.body["status"], "pending");
+    let stock = app.get(&format!("/api/v1/products/{}/inventory", laptop), Some(&token)).await;
+    assert_eq!(stock.body["quantity"], i32::MAX - 1);
+}
+
+#[tokio::test]
+async fn order_history_is_per_user() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let laptop = stocked_product(&app, &admin, "Laptop", 1200, 5).await;
+    let (alice_id, alice) = app.user("alice").await;
+    let (_, bob) = app.user("bob").await;
+
+    let response = checkout(&app, &alice, json!([{ "product_id": laptop, "quantity": 1 }])).await;
+    let alice_order = response.body["id"].as_i64().unwrap();
+    checkout(&app, &alice, json!([{ "product_id": laptop, "quantity": 1 }])).await;
+    checkout(&app, &bob, json!([{ "product_id": laptop, "quantity": 1 }])).await;
+
+    let orders = app.get("/api/v1/orders", Some(&alice)).await;
+    assert_eq!(orders.status, StatusCode::OK);
+    assert_eq!(orders.body["total"], 2);
+    assert!(orders.body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|order| order["user_id"] == alice_id));
+
+    assert_eq!(app.get("/api/v1/orders", Some(&bob)).await.body["total"], 1);
+    assert_eq!(
+        app.get(&format!("/api/v1/orders?user_id={}", alice_id), Some(&bob)).await.status,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        app.get(&format!("/api/v1/orders/{}", alice_order), Some(&bob)).await.status,
+        StatusCode::FORBIDDEN
+    );
+
+    // 관리자는 모든 사용자의 주문을 조회할 수 있다.
+    assert_eq!(app.get("/api/v1/orders", Some(&admin)).await.body["total"], 3);
+    let filtered = app
+        .get(&format!("/api/v1/orders?user_id={}&status=pending", alice_id), Some(&admin))
+        .await;
+    assert_eq!(filtered.body["total"], 2);
+}
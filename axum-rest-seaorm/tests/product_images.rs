@@ -0,0 +1,215 @@
+mod common;
+
+use std::io::Cursor;
+
+use axum::http::{header, Method, StatusCode};
+use common::{TestApp, TestResponse};
+use image::{DynamicImage, ImageFormat, Rgb, RgbImage, Rgba, RgbaImage};
+
+const BOUNDARY: &str = "test-boundary";
+
+fn png(width: u32, height: u32) -> Vec<u8> {
+    encode(DynamicImage::ImageRgba8(RgbaImage::from_pixel(width, height, Rgba([200, 10, 10, 128]))), ImageFormat::Png)
+}
+
+fn jpeg(width: u32, height: u32) -> Vec<u8> {
+    encode(DynamicImage::ImageRgb8(RgbImage::from_pixel(width, height, Rgb([10, 200, 10]))), ImageFormat::Jpeg)
+}
+
+fn encode(image: DynamicImage, format: ImageFormat) -> Vec<u8> {
+    let mut out = Cursor::new(Vec::new());
+    image.write_to(&mut out, format).unwrap();
+    out.into_inner()
+}
+
+// 파일 하나를 'file' 필드로 담은 multipart 본문 (Content-Type은 일부러 틀리게 보낸다)
+fn multipart(file_name: &str, content: &[u8]) -> Vec<u8> {
+    let mut body = format!(
+        "--{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{f}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n",
+        b = BOUNDARY,
+        f = file_name
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+    body
+}
+
+async fn upload(app: &TestApp, token: &str, product_id: i64, file_name: &str, content: &[u8]) -> TestResponse {
+    app.request_raw(
+        Method::POST,
+        &format!("/api/v1/products/{}/images", product_id),
+        Some(token),
+        &format!("multipart/form-data; boundary={}", BOUNDARY),
+        multipart(file_name, content),
+    )
+    .await
+}
+
+async fn get_with(app: &TestApp, uri: &str, token: &str, name: header::HeaderName, value: &str) -> TestResponse {
+    app.request_with_headers(Method::GET, uri, Some(token), &[(name, value)]).await
+}
+
+async fn product(app: &TestApp, admin: &str) -> i64 {
+    app.create_category(admin, "Electronics").await;
+    app.create_product(admin, "Laptop", 1200, "Electronics").await["id"].as_i64().unwrap()
+}
+
+#[tokio::test]
+async fn upload_stores_image_and_thumbnail() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    let id = product(&app, &admin).await;
+    let original = png(800, 400);
+
+    let response = upload(&app, &admin, id, "laptop.png", &original).await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["content_type"], "image/png");
+    assert_eq!(response.body["file_name"], "laptop.png");
+    assert_eq!(response.body["width"], 800);
+    assert_eq!(response.body["height"], 400);
+    assert_eq!(response.body["byte_size"], original.len());
+    let location = response.header(header::LOCATION).unwrap().to_string();
+    let url = response.body["url"].as_str().unwrap().to_string();
+    let thumbnail_url = response.body["thumbnail_url"].as_str().unwrap().to_string();
+
+    let metadata = app.get(&location, Some(&token)).await;
+    assert_eq!(metadata.status, StatusCode::OK);
+    assert_eq!(metadata.body["url"], url.as_str());
+
+    let content = app.get(&url, Some(&token)).await;
+    assert_eq!(content.status, StatusCode::OK);
+    assert_eq!(content.header(header::CONTENT_TYPE), Some("image/png"));
+    assert_eq!(content.header(header::ACCEPT_RANGES), Some("bytes"));
+    assert!(content.header(header::ETAG).is_some());
+    assert_eq!(content.bytes, original);
+
+    // 썸네일은 비율을 유지하며 긴 변이 storage.thumbnail_size (기본 256)
+    let thumbnail = app.get(&thumbnail_url, Some(&token)).await;
+    assert_eq!(thumbnail.status, StatusCode::OK);
+    assert_eq!(thumbnail.header(header::CONTENT_TYPE), Some("image/png"));
+    let decoded = image::load_from_memory(&thumbnail.bytes).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (256, 128));
+
+    let list = app.get(&format!("/api/v1/products/{}/images", id), Some(&token)).await;
+    assert_eq!(list.status, StatusCode::OK);
+    assert_eq!(list.body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn jpeg_thumbnail_stays_jpeg() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+
+    // 파일 이름, Content-Type과 상관없이 내용으로 형식을 판단한다.
+    let response = upload(&app, &admin, id, "photo.png", &jpeg(300, 600)).await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["content_type"], "image/jpeg");
+
+    let thumbnail = app.get(response.body["thumbnail_url"].as_str().unwrap(), Some(&admin)).await;
+    assert_eq!(thumbnail.header(header::CONTENT_TYPE), Some("image/jpeg"));
+    let decoded = image::load_from_memory(&thumbnail.bytes).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (128, 256));
+}
+
+#[tokio::test]
+async fn image_serving_supports_etag_and_range() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let id = product(&app, &admin).await;
+    let original = png(64, 64);
+    let size = original.len();
+
+    let response = upload(&app, &admin, id, "small.png", &original).await;
+    let url = response.body["url"].as_str().unwrap().to_string();
+    let etag = app.get(&url, Some(&admin)).await.header(header::ETAG).unwrap().to_string();
+
+    let response = get_with(&app, &url, &admin, header::IF_NONE_MATCH, &etag).await;
+    assert_eq!(response.status, StatusCode::NOT_MODIFIED);
+    assert!(response.bytes.is_empty());
+    let response = get_with(&app, &url, &admin, header::IF_NONE_MATCH, "\"other\"").await;
+    assert_eq!(response.status, StatusCode::OK);
+
+    let response = get_with(&app, &url, &admin, header::RANGE, "bytes=0-9").await;
+    assert_eq!(response.status, StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.header(header::CONTENT_RANGE), Some(format!("bytes 0-9/{}", size).as_str()));
+    assert_eq!(response.bytes, original[..10]);
+
+    let response = get_with(&app, &url, &admin, header::RANGE, "bytes=-5").await;
+    assert_eq!(response.status, StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.bytes, original[size - 5..]);
+
+    let response = get_with(&app, &url, &admin, header::RANGE, &format!("bytes=10-{}", size * 2)).await;
+    assert_eq!(response.status, StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.bytes, original[10..]);
+
+    let response = get_with(&app, &url, &admin, header::RANGE, &format!("bytes={}-", size)).await;
+    assert_eq!(response.status, StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.header(header::CONTENT_RANGE), Some(format!("bytes */{}", size).as_str()));
+
+    // 해석할 수 없는 Range는 무시하고 전체를 보낸다.
+    let response = get_with(&app, &url, &admin, header::RANGE, "bytes=0-1,5-6").await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.bytes, original);
+}
+
+#[tokio::test]
+async fn upload_rejects_invalid_files() {
+    let app = TestApp::with_config(|config| config.storage.max_image_bytes = 4096).await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    let id = product(&app, &admin).await;
+
+    let response = upload(&app, &token, id, "a.png", &png(8, 8)).await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+    let response = upload(&app, &admin, 9999, "a.png", &png(8, 8)).await;
+    assert_eq!(response.status, StatusCode::NOT_FOUND);
+
+    let response = upload(&app, &admin, id, "a.png", b"definitely not an image").await;
+    assert_eq!(response.status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(response.body["code"], "unsupported_media_type");
+
+    // PNG signature 뒤가 깨진 파일
+    let mut corrupt = png(8, 8)[..16].to_vec();
+    corrupt.extend_from_slice(&[0; 64]);
+    let response = upload(&app, &admin, id, "a.png", &corrupt).await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.body["errors"][0]["field"], "file");
+
+    let mut large = png(8, 8);
+    large.resize(large.len() + 8192, 0);
+    let response = upload(&app, &admin, id, "a.png", &large).await;
+    assert_eq!(response.status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    let list = app.get(&format!("/api/v1/products/{}/images", id), Some(&admin)).await;
+    assert_eq!(list.body.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn delete_image_removes_files() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, token) = app.user("alice").await;
+    let id = product(&app, &admin).await;
+
+    let response = upload(&app, &admin, id, "a.png", &png(32, 32)).await;
+    let location = response.header(header::LOCATION).unwrap().to_string();
+    let root = &app.state.config.storage.local_root;
+    let files = |dir: &std::path::Path| std::fs::read_dir(dir).map(|entries| entries.count()).unwrap_or(0);
+    assert_eq!(files(&root.join(format!("products/{}", id))), 2);
+
+    let response = app.request(Method::DELETE, &location, Some(&token), None).await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+
+    let response = app.request(Method::DELETE, &location, Some(&admin), None).await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    assert_eq!(files(&root.join(format!("products/{}", id))), 0);
+    assert_eq!(app.get(&location, Some(&admin)).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(
+        app.get(&format!("{}/content", location), Some(&admin)).await.status,
+        StatusCode::NOT_FOUND
+    );
+}
@@ -0,0 +1,501 @@
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use common::TestApp;
+use serde_json::json;
+
+#[tokio::test]
+async fn create_product_requires_admin() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&token),
+            Some(json!({ "title": "Laptop", "price": 1200, "category": "Electronics" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn create_and_get_product() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": "Laptop", "price": 1200, "category": "Electronics" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    let id = response.body["id"].as_i64().unwrap();
+    let location = format!("/api/v1/products/{}", id);
+    assert_eq!(response.header(header::LOCATION), Some(location.as_str()));
+
+    let found = app.get(&location, Some(&admin)).await;
+    assert_eq!(found.status, StatusCode::OK);
+    assert_eq!(found.body["title"], "Laptop");
+    assert_eq!(found.body["price"], "1200");
+    assert_eq!(found.body["currency"], "KRW");
+    assert_eq!(found.body["category"], "Electronics");
+}
+
+#[tokio::test]
+async fn create_product_validates_fields() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": " ", "price": "-1" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let fields: Vec<&str> = response.body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["field"].as_str().unwrap())
+        .collect();
+    assert!(fields.contains(&"title"), "{:?}", fields);
+    assert!(fields.contains(&"price"), "{:?}", fields);
+}
+
+#[tokio::test]
+async fn create_product_with_unknown_category_conflicts() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": "Laptop", "price": 1200, "category": "Missing" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn get_product_rejects_invalid_and_missing_id() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+
+    assert_eq!(app.get("/api/v1/products/abc", Some(&token)).await.status, StatusCode::BAD_REQUEST);
+    assert_eq!(app.get("/api/v1/products/9999", Some(&token)).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn update_and_replace_product() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let product = app.create_product(&admin, "Laptop", 1200, "Electronics").await;
+    let uri = format!("/api/v1/products/{}", product["id"]);
+
+    let patched = app
+        .request(Method::PATCH, &uri, Some(&admin), Some(json!({ "price": 999 })))
+        .await;
+    assert_eq!(patched.status, StatusCode::OK);
+    assert_eq!(patched.body["price"], "999");
+    assert_eq!(patched.body["title"], "Laptop");
+
+    let replaced = app
+        .request(
+            Method::PUT,
+            &uri,
+            Some(&admin),
+            Some(json!({ "title": "Notebook", "price": 1500, "category": "Electronics" })),
+        )
+        .await;
+    assert_eq!(replaced.status, StatusCode::OK);
+    assert_eq!(replaced.body["title"], "Notebook");
+    assert_eq!(replaced.body["price"], "1500");
+
+    // PUT은 전체 교체이므로 필드가 빠지면 422
+    let incomplete = app
+        .request(Method::PUT, &uri, Some(&admin), Some(json!({ "title": "Notebook" })))
+        .await;
+    assert_eq!(incomplete.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn delete_and_restore_product() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    let product = app.create_product(&admin, "Laptop", 1200, "Electronics").await;
+    let uri = format!("/api/v1/products/{}", product["id"]);
+
+    let deleted = app.request(Method::DELETE, &uri, Some(&admin), None).await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.get(&uri, Some(&admin)).await.status, StatusCode::NOT_FOUND);
+
+    let with_deleted = app.get(&format!("{}?include_deleted=true", uri), Some(&admin)).await;
+    assert_eq!(with_deleted.status, StatusCode::OK);
+    assert!(!with_deleted.body["deleted_at"].is_null());
+
+    let restored = app
+        .request(Method::POST, &format!("{}/restore", uri), Some(&admin), None)
+        .await;
+    assert_eq!(restored.status, StatusCode::OK);
+    assert!(restored.body["deleted_at"].is_null());
+    assert_eq!(app.get(&uri, Some(&admin)).await.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn bulk_delete_requires_non_blank_filter_and_confirm() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    app.create_product(&admin, "Laptop", 1200, "Electronics").await;
+    app.create_product(&admin, "Phone", 800, "Electronics").await;
+
+    // 빈 필터는 모든 product에 일치하므로 거부
+    for query in ["", "?confirm=2", "?title=&confirm=2", "?title=%20%20&confirm=2", "?category=&confirm=2"] {
+        let response = app
+            .request(Method::DELETE, &format!("/api/v1/products{}", query), Some(&admin), None)
+            .await;
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", query);
+    }
+
+    let preview = app
+        .request(Method::DELETE, "/api/v1/products?title=Lap&dry_run=true", Some(&admin), None)
+        .await;
+    assert_eq!(preview.status, StatusCode::OK);
+    assert_eq!(preview.body["matched"], 1);
+
+    let mismatch = app
+        .request(Method::DELETE, "/api/v1/products?title=Lap&confirm=2", Some(&admin), None)
+        .await;
+    assert_eq!(mismatch.status, StatusCode::CONFLICT);
+
+    let deleted = app
+        .request(Method::DELETE, "/api/v1/products?title=Lap&confirm=1", Some(&admin), None)
+        .await;
+    assert_eq!(deleted.status, StatusCode::OK);
+    assert_eq!(deleted.body["deleted"], 1);
+    assert_eq!(app.get("/api/v1/products", Some(&admin)).await.body["total"], 1);
+}
+
+#[tokio::test]
+async fn list_products_filters_and_sorts() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    app.create_category(&admin, "Books").await;
+    app.create_product(&admin, "Laptop", 1200, "Electronics").await;
+    app.create_product(&admin, "Phone", 800, "Electronics").await;
+    app.create_product(&admin, "Novel", 20, "Books").await;
+
+    let sorted = app.get("/api/v1/products?sort=-price", Some(&admin)).await;
+    assert_eq!(sorted.status, StatusCode::OK);
+    let prices: Vec<&str> = sorted.body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|product| product["price"].as_str().unwrap())
+        .collect();
+    assert_eq!(prices, ["1200", "800", "20"]);
+
+    let filtered = app
+        .get("/api/v1/products?category=Electronics&price_lte=1000", Some(&admin))
+        .await;
+    assert_eq!(filtered.status, StatusCode::OK);
+    assert_eq!(filtered.body["total"], 1);
+    assert_eq!(filtered.body["items"][0]["title"], "Phone");
+
+    let invalid = app.get("/api/v1/products?sort=secret", Some(&admin)).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn list_products_sets_pagination_link_header() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    for title in ["Laptop", "Phone", "Tablet"] {
+        app.create_product(&admin, title, 100, "Electronics").await;
+    }
+
+    let response = app.get("/api/v1/products?per_page=1&page=2", Some(&admin)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["total"], 3);
+    // nest된 router 안에서도 /api/v1 prefix가 포함된 URL이어야 한다.
+    assert_eq!(
+        response.header(header::LINK),
+        Some(
+            "</api/v1/products?per_page=1&page=1>; rel=\"first\", \
+             </api/v1/products?per_page=1&page=1>; rel=\"prev\", \
+             </api/v1/products?per_page=1&page=3>; rel=\"next\", \
+             </api/v1/products?per_page=1&page=3>; rel=\"last\""
+        )
+    );
+}
+
+#[tokio::test]
+async fn list_products_rejects_invalid_page() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+
+    for query in [
+        "page=0",
+        "page=abc",
+        "per_page=101",
+        "page=18446744073709551615",
+        "page=92233720368547760&per_page=100",
+    ] {
+        let response = app.get(&format!("/api/v1/products?{}", query), Some(&token)).await;
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", query);
+    }
+
+    // offset이 i64 범위 안이면 빈 페이지를 돌려준다.
+    let response = app.get("/api/v1/products?page=92233720368547759&per_page=100", Some(&token)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["items"].as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn search_products_ranks_prefix_matches() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Electronics").await;
+    app.create_product(&admin, "Wireless Mouse", 30, "Electronics").await;
+    app.create_product(&admin, "Mechanical Keyboard", 90, "Electronics").await;
+    app.create_product(&admin, "Ergonomic Keyboard Tray", 50, "Electronics").await;
+    let deleted = app.create_product(&admin, "Keyboard Cover", 10, "Electronics").await;
+    app.request(
+        Method::DELETE,
+        &format!("/api/v1/products/{}", deleted["id"]),
+        Some(&admin),
+        None,
+    )
+    .await;
+
+    let response = app.get("/api/v1/products/search?q=keyb", Some(&admin)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["query"], "keyb");
+    let titles: Vec<&str> = response.body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["Mechanical Keyboard", "Ergonomic Keyboard Tray"]);
+    assert_eq!(
+        response.body["items"][0]["highlight"],
+        "Mechanical <mark>Keyboard</mark>"
+    );
+
+    // 모든 단어를 포함해야 하며, 단어 시작 부분에서 일치하는 product가 먼저 온다.
+    let response = app
+        .get("/api/v1/products/search?q=MECH%20board&limit=5", Some(&admin))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["items"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        response.body["items"][0]["highlight"],
+        "<mark>Mechanical</mark> <mark>Keyboard</mark>"
+    );
+}
+
+// Postgres 전문 검색 (tsvector, ts_rank, ts_headline)
+// DATABASE_URL=postgres://... cargo test --test products -- --ignored --test-threads=1
+#[tokio::test]
+#[ignore = "requires DATABASE_URL pointing to a test Postgres DB"]
+async fn search_products_uses_postgres_full_text_search() {
+    let app = TestApp::postgres().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+    app.create_product(&admin, "Keyboard Stand", 20, "Books").await;
+    app.create_product(&admin, "Keyboard Keyboard Bundle", 120, "Books").await;
+    app.create_product(&admin, "O'Reilly & Sons Keyboard Guide", 40, "Books").await;
+
+    // 단어가 더 자주 나오는 product가 먼저 온다.
+    let response = app.get("/api/v1/products/search?q=keyb", Some(&admin)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    let items = response.body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0]["title"], "Keyboard Keyboard Bundle");
+    assert!(items[0]["rank"].as_f64().unwrap() > items[1]["rank"].as_f64().unwrap());
+    assert_eq!(items[0]["highlight"], "<mark>Keyboard</mark> <mark>Keyboard</mark> Bundle");
+
+    // tsquery 문법 문자(', &, |, !, :)가 섞여 있어도 syntax error 없이 검색된다.
+    let response = app
+        .get("/api/v1/products/search?q=o'reilly%20%26%20keyb", Some(&admin))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    let items = response.body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0]["highlight"],
+        "<mark>O</mark>'<mark>Reilly</mark> & Sons <mark>Keyboard</mark> Guide"
+    );
+
+    let response = app
+        .get("/api/v1/products/search?q=%27%27stand%3A*%20%7C%20!bundle", Some(&admin))
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    let titles: Vec<&str> = response.body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["title"].as_str().unwrap())
+        .collect();
+    assert!(titles.is_empty(), "{:?}", titles);
+}
+
+#[tokio::test]
+async fn search_products_validates_query() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+
+    let missing = app.get("/api/v1/products/search", Some(&admin)).await;
+    assert_eq!(missing.status, StatusCode::BAD_REQUEST);
+
+    // 문자/숫자가 없는 검색어 (wildcard만 입력)
+    let wildcard = app.get("/api/v1/products/search?q=%25_", Some(&admin)).await;
+    assert_eq!(wildcard.status, StatusCode::BAD_REQUEST);
+
+    let limit = app.get("/api/v1/products/search?q=a&limit=101", Some(&admin)).await;
+    assert_eq!(limit.status, StatusCode::BAD_REQUEST);
+
+    let unauthorized = app.get("/api/v1/products/search?q=a", None).await;
+    assert_eq!(unauthorized.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn product_price_is_a_decimal_string_with_currency() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    app.create_category(&admin, "Books").await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": "eBook", "price": "19.99", "currency": "USD", "category": "Books" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    assert_eq!(response.body["price"], "19.99");
+    assert_eq!(response.body["currency"], "USD");
+
+    let free = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": "Sample", "price": "0", "category": "Books" })),
+        )
+        .await;
+    assert_eq!(free.status, StatusCode::CREATED);
+
+    let filtered = app
+        .get("/api/v1/products?currency=USD&price_gte=19.5", Some(&admin))
+        .await;
+    assert_eq!(filtered.status, StatusCode::OK);
+    assert_eq!(filtered.body["total"], 1);
+    assert_eq!(filtered.body["items"][0]["title"], "eBook");
+
+    let invalid = [
+        // 부동소수점 number는 거부 (문자열로 전달해야 함)
+        json!({ "title": "A", "price": 19.99, "category": "Books" }),
+        json!({ "title": "A", "price": "-0.01", "currency": "USD", "category": "Books" }),
+        json!({ "title": "A", "price": "abc", "category": "Books" }),
+        // KRW는 소수점 이하 금액이 없다.
+        json!({ "title": "A", "price": "10.5", "currency": "KRW", "category": "Books" }),
+        json!({ "title": "A", "price": "10", "currency": "XYZ", "category": "Books" }),
+    ];
+    for body in invalid {
+        let response = app
+            .request(Method::POST, "/api/v1/products", Some(&admin), Some(body.clone()))
+            .await;
+        assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    }
+
+    // null은 값이 없는 것과 같다: 생성 시에는 필수 필드 누락, PATCH에서는 변경하지 않음
+    let response = app
+        .request(
+            Method::POST,
+            "/api/v1/products",
+            Some(&admin),
+            Some(json!({ "title": "A", "price": null, "category": "Books" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.body["errors"][0]["field"], "price", "{}", response.body);
+
+    let patched = app
+        .request(
+            Method::PATCH,
+            &format!("/api/v1/products/{}", free.body["id"]),
+            Some(&admin),
+            Some(json!({ "title": "Free sample", "price": null })),
+        )
+        .await;
+    assert_eq!(patched.status, StatusCode::OK, "{}", patched.body);
+    assert_eq!(patched.body["title"], "Free sample");
+    assert_eq!(patched.body["price"], "0");
+}
+
+#[tokio::test]
+async fn price_changes_are_recorded_in_history() {
+    let app = TestApp::new().await;
+    let admin = app.admin_token().await;
+    let (_, user) = app.user("alice").await;
+    app.create_category(&admin, "Electronics").await;
+    let product = app.create_product(&admin, "Laptop", 1200, "Electronics").await;
+    let uri = format!("/api/v1/products/{}", product["id"]);
+
+    // 가격이 바뀌지 않는 변경은 기록하지 않는다.
+    app.request(Method::PATCH, &uri, Some(&admin), Some(json!({ "title": "Notebook" })))
+        .await;
+    app.request(Method::PATCH, &uri, Some(&admin), Some(json!({ "price": "1100" })))
+        .await;
+    let changed = app
+        .request(
+            Method::PUT,
+            "/product",
+            Some(&admin),
+            Some(json!({ "id": product["id"], "price": "899.50", "currency": "USD" })),
+        )
+        .await;
+    assert_eq!(changed.status, StatusCode::OK);
+
+    // PATCH로 currency만 바꿀 때도 기존 금액의 소수점 자릿수를 검사한다.
+    let invalid = app
+        .request(Method::PATCH, &uri, Some(&admin), Some(json!({ "currency": "KRW" })))
+        .await;
+    assert_eq!(invalid.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let history = app.get(&format!("{}/price-history", uri), Some(&user)).await;
+    assert_eq!(history.status, StatusCode::OK);
+    let entries = history.body.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["old_price"], "1100");
+    assert_eq!(entries[0]["old_currency"], "KRW");
+    assert_eq!(entries[0]["new_price"], "899.5");
+    assert_eq!(entries[0]["new_currency"], "USD");
+    assert_eq!(entries[1]["old_price"], "1200");
+    assert_eq!(entries[1]["new_price"], "1100");
+    assert!(entries[1]["changed_by"].is_number());
+
+    let missing = app.get("/api/v1/products/999/price-history", Some(&user)).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use common::TestApp;
+use serde_json::json;
+
+#[tokio::test]
+async fn list_users_is_paginated() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+    app.user("bob").await;
+
+    let response = app.get("/api/v1/users?per_page=2&sort=username", Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    // admin + alice + bob
+    assert_eq!(response.body["total"], 3);
+    assert_eq!(response.header(header::HeaderName::from_static("x-total-count")), Some("3"));
+    let usernames: Vec<&str> = response.body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["username"].as_str().unwrap())
+        .collect();
+    assert_eq!(usernames, ["admin", "alice"]);
+}
+
+#[tokio::test]
+async fn list_users_rejects_unknown_sort_field() {
+    let app = TestApp::new().await;
+    let (_, token) = app.user("alice").await;
+
+    let response = app.get("/api/v1/users?sort=password", Some(&token)).await;
+
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_user_by_id() {
+    let app = TestApp::new().await;
+    let (id, token) = app.user("alice").await;
+
+    let found = app.get(&format!("/api/v1/users/{}", id), Some(&token)).await;
+    let missing = app.get("/api/v1/users/9999", Some(&token)).await;
+    let invalid = app.get("/api/v1/users/abc", Some(&token)).await;
+
+    assert_eq!(found.status, StatusCode::OK);
+    assert_eq!(found.body["username"], "alice");
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn user_can_update_own_account_only() {
+    let app = TestApp::new().await;
+    let (alice_id, alice_token) = app.user("alice").await;
+    let (bob_id, _) = app.user("bob").await;
+
+    let own = app
+        .request(
+            Method::PATCH,
+            &format!("/api/v1/users/{}", alice_id),
+            Some(&alice_token),
+            Some(json!({ "username": "alice2" })),
+        )
+        .await;
+    assert_eq!(own.status, StatusCode::OK);
+    assert_eq!(own.body["username"], "alice2");
+
+    let other = app
+        .request(
+            Method::PATCH,
+            &format!("/api/v1/users/{}", bob_id),
+            Some(&alice_token),
+            Some(json!({ "username": "hacked" })),
+        )
+        .await;
+    assert_eq!(other.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn password_change_takes_effect() {
+    let app = TestApp::new().await;
+    let (id, token) = app.user("alice").await;
+    let refresh_token = app.login("alice", "user-password1").await.body["refresh_token"].clone();
+
+    let response = app
+        .request(
+            Method::PUT,
+            &format!("/api/v1/users/{}", id),
+            Some(&token),
+            Some(json!({ "username": "alice", "password": "new-password1" })),
+        )
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+
+    assert_eq!(app.login("alice", "user-password1").await.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(app.login("alice", "new-password1").await.status, StatusCode::OK);
+
+    // password 변경 전에 발급된 refresh token은 더 이상 사용할 수 없다.
+    let refreshed = app
+        .request(Method::POST, "/api/v1/auth/refresh", None, Some(json!({ "refresh_token": refresh_token })))
+        .await;
+    assert_eq!(refreshed.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn delete_user_requires_admin() {
+    let app = TestApp::new().await;
+    let (id, token) = app.user("alice").await;
+    let admin = app.admin_token().await;
+    let uri = format!("/api/v1/users/{}", id);
+
+    let forbidden = app.request(Method::DELETE, &uri, Some(&token), None).await;
+    assert_eq!(forbidden.status, StatusCode::FORBIDDEN);
+
+    let deleted = app.request(Method::DELETE, &uri, Some(&admin), None).await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+
+    assert_eq!(app.get(&uri, Some(&admin)).await.status, StatusCode::NOT_FOUND);
+}
@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{env, future::IntoFuture, sync::Arc, time::Duration};
 use axum::{
     body::Body,
     extract::{
-        ws::{Message, WebSocket},
+        ws::{close_code, CloseFrame, Message, WebSocket},
         State,WebSocketUpgrade,
     },
     http::{HeaderMap, Request, StatusCode},
@@ -16,15 +16,25 @@ use futures_util::{
     sink::SinkExt, 
     stream::{StreamExt, SplitSink, SplitStream},
 };
-use tokio::sync::{
-    broadcast::{self, Receiver, Sender},
-    Mutex,
+use tokio::{
+    signal,
+    sync::{
+        broadcast::{self, Receiver, Sender},
+        watch, Mutex, Notify,
+    },
 };
+use tracing::{error, info, warn};
+use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
+// 종료 signal 후 연결이 정리되기를 기다리는 기본 시간 (SHUTDOWN_TIMEOUT_SECS로 변경)
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 10;
 
 #[derive(Debug, Clone)]
 struct AppState {
     broadcast_tx: Arc<Mutex<Sender<Message>>>,
+    // 서버 종료 시 true로 바뀜
+    // -- 각 WebSocket 연결이 clone을 들고 있으므로 모든 clone이 drop되면 연결이 모두 정리된 것
+    shutdown_rx: watch::Receiver<bool>,
 }
 
 async fn websocket_handler(ws: WebSocketUpgrade, State(app_state): State<AppState>) -> impl IntoResponse {
@@ -56,12 +66,14 @@ async fn handle_websocket(ws: WebSocket, app_state: AppState) {
     {
         // app_state.broadcast_tx.lock()의 결과물(MutexGuard)은 이 블록 안에서만 유효합니다.
         let broadcast_rx = app_state.broadcast_tx.lock().await.subscribe();
+        let shutdown_rx = app_state.shutdown_rx.clone();
         tokio::spawn(async move {
-            send_to_websocket(ws_tx, broadcast_rx).await;
+            send_to_websocket(ws_tx, broadcast_rx, shutdown_rx).await;
         });
     } // <-- 이 지점에서 lock이 해제됨
     
     // 2. 클라이언트로부터 오는 메시지를 처리하는 루프를 'await' 함
+    // -- 서버 종료 시에는 클라이언트가 close frame에 응답할 때까지 기다린다. (drain deadline까지)
     receive_from_websocket(ws_rx, app_state.broadcast_tx).await;
 
     // 3. (연결 종료 후) 후처리 코드 (예: 로그 남기기)
@@ -100,10 +112,25 @@ async fn handle_websocket(ws: WebSocket, app_state: AppState) {
 async fn send_to_websocket(
     client_tx: Arc<Mutex<SplitSink<WebSocket, Message>>>,
     mut broadcast_rx: Receiver<Message>,
+    mut shutdown_rx: watch::Receiver<bool>,
 ) {
-    while let Ok(msg) = broadcast_rx.recv().await {
-        if client_tx.lock().await.send(msg).await.is_err() {
-            return;
+    loop {
+        tokio::select! {
+            msg = broadcast_rx.recv() => {
+                let Ok(msg) = msg else { return };
+                if client_tx.lock().await.send(msg).await.is_err() {
+                    return;
+                }
+            }
+            // 서버 종료: 클라이언트에게 close frame(1001 Going Away)을 보낸다.
+            _ = async { let _ = shutdown_rx.wait_for(|stopping| *stopping).await; } => {
+                let close = Message::Close(Some(CloseFrame {
+                    code: close_code::AWAY,
+                    reason: "server shutting down".into(),
+                }));
+                let _ = client_tx.lock().await.send(close).await;
+                return;
+            }
         }
     }
 }
@@ -137,11 +164,48 @@ async fn authenticate(
     }
 }
 
+// Ctrl+C (SIGINT) 또는 SIGTERM을 기다린다.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
 #[tokio::main]
 async fn main() {
+    // RUST_LOG가 없으면 info 이상을 출력
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .init();
+
+    let shutdown_timeout = Duration::from_secs(
+        env::var("SHUTDOWN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_SECS),
+    );
+
     let (tx, _) = broadcast::channel(32);
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
     let app_state = AppState {
         broadcast_tx: Arc::new(Mutex::new(tx)),
+        shutdown_rx,
     };
 
     let app = Router::new().route("/ws", get(websocket_handler))
@@ -149,7 +213,40 @@ async fn main() {
         .with_state(app_state);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:8000").await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+
+    // WebSocket은 upgrade 이후 axum::serve가 추적하지 않으므로
+    // 1. signal을 받으면 shutdown_tx로 모든 연결에 close frame을 보내게 하고
+    // 2. axum::serve가 끝난 뒤 모든 연결(shutdown_rx clone)이 drop될 때까지 기다린다.
+    // -- 전체 과정은 shutdown_timeout을 넘기지 않는다.
+    let signaled = Arc::new(Notify::new());
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown({
+            let shutdown_tx = shutdown_tx.clone();
+            let signaled = signaled.clone();
+            async move {
+                shutdown_signal().await;
+                info!("Shutting down, closing WebSocket connections...");
+                let _ = shutdown_tx.send(true);
+                signaled.notify_one();
+            }
+        })
+        .into_future();
+
+    let drain = async {
+        if let Err(e) = server.await {
+            error!("Server error: {}", e);
+        }
+        shutdown_tx.closed().await;
+    };
+    let deadline = async {
+        signaled.notified().await;
+        tokio::time::sleep(shutdown_timeout).await;
+    };
+
+    tokio::select! {
+        _ = drain => info!("All connections closed"),
+        _ = deadline => warn!("Shutdown timed out after {:?}, dropping remaining connections", shutdown_timeout),
+    }
 }
 
 